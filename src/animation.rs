@@ -0,0 +1,298 @@
+use crate::frames::Frame;
+use crate::frames::Frames;
+use crate::Error;
+use crate::Result;
+use std::fmt::Write as _;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::ops::ControlFlow;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+
+/// A command typed while an animation plays, followed by Enter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// `p` or an empty line: pause or resume.
+    Pause,
+    /// `n`: show the next frame and pause.
+    Step,
+    /// `+`: halve the delay between frames.
+    Faster,
+    /// `-`: double the delay between frames.
+    Slower,
+    /// `q`: stop the animation, ending the run.
+    Quit,
+}
+
+impl Control {
+    pub fn parse(line: &str) -> Option<Control> {
+        match line.trim() {
+            "" | "p" => Some(Control::Pause),
+            "n" => Some(Control::Step),
+            "+" => Some(Control::Faster),
+            "-" => Some(Control::Slower),
+            "q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Reads controls from stdin on a background thread until it is closed.
+pub fn stdin_controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Some(control) = Control::parse(&line) {
+                if sender.send(control).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    receiver
+}
+
+/// Plays frames in a terminal, redrawing a viewport of `width` by
+/// `height` characters in place with ANSI escape codes. The viewport
+/// scrolls to keep the focus of each frame away from its edges.
+pub struct Animation<'a, W: Write> {
+    out: W,
+    controls: Option<&'a Receiver<Control>>,
+    delay: Duration,
+    width: usize,
+    height: usize,
+    origin: (usize, usize),
+    paused: bool,
+    stopped: bool,
+    shown: usize,
+}
+
+impl<'a, W: Write> Animation<'a, W> {
+    pub fn new(out: W, delay: Duration, width: usize, height: usize) -> Self {
+        Animation {
+            out,
+            controls: None,
+            delay,
+            width: width.max(1),
+            height: height.max(1),
+            origin: (0, 0),
+            paused: false,
+            stopped: false,
+            shown: 0,
+        }
+    }
+
+    pub fn with_controls(mut self, controls: &'a Receiver<Control>) -> Self {
+        self.controls = Some(controls);
+        self
+    }
+
+    /// Whether the controls quit the animation. The simulation still runs
+    /// to its end, but its remaining frames are neither drawn nor shown.
+    pub fn stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// The number of frames shown so far.
+    pub fn shown(&self) -> usize {
+        self.shown
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Moves the viewport to the focus of `frame`, keeping it inside the
+    /// picture where possible.
+    fn follow(&mut self, frame: &Frame) {
+        let (mut x, mut y) = self.origin;
+        if let Some((fx, fy)) = frame.focus {
+            x = scroll(x, fx, self.width);
+            y = scroll(y, fy, self.height);
+        }
+        let picture = &frame.picture;
+        self.origin = (
+            x.min(picture.width().saturating_sub(self.width)),
+            y.min(picture.height().saturating_sub(self.height)),
+        );
+    }
+
+    fn show(&mut self, frame: &Frame) -> Result<()> {
+        self.follow(frame);
+        let mut screen = String::new();
+        if self.shown == 0 {
+            // Clear the screen and hide the cursor.
+            screen += "\x1b[2J\x1b[?25l";
+        }
+        screen += "\x1b[H";
+        let (x, y) = self.origin;
+        let picture = &frame.picture;
+        for row in y..(y + self.height).min(picture.height()) {
+            screen.extend(picture.row(row).iter().skip(x).take(self.width));
+            screen += "\x1b[K\n";
+        }
+        self.shown += 1;
+        let paused = if self.paused { ", paused" } else { "" };
+        write!(
+            screen,
+            "frame {}, delay {:?}{}\x1b[K\n\x1b[J",
+            self.shown, self.delay, paused
+        )
+        .unwrap();
+        self.write(&screen)
+    }
+
+    /// Shows the cursor again.
+    fn finish(&mut self) -> Result<()> {
+        self.write("\x1b[?25h")
+    }
+
+    fn write(&mut self, text: &str) -> Result<()> {
+        self.out
+            .write_all(text.as_bytes())
+            .and_then(|()| self.out.flush())
+            .map_err(|source| Error::Io {
+                path: "<stdout>".to_string(),
+                source,
+            })
+    }
+
+    /// Waits for the delay between frames, or while paused, handling the
+    /// controls typed meanwhile. Breaks if they quit the animation.
+    fn wait(&mut self) -> Result<ControlFlow<()>> {
+        loop {
+            let control = match self.controls {
+                None => {
+                    thread::sleep(self.delay);
+                    return Ok(ControlFlow::Continue(()));
+                }
+                Some(controls) if self.paused => controls.recv().ok(),
+                Some(controls) => match controls.recv_timeout(self.delay) {
+                    Ok(control) => Some(control),
+                    Err(RecvTimeoutError::Timeout) => return Ok(ControlFlow::Continue(())),
+                    Err(RecvTimeoutError::Disconnected) => None,
+                },
+            };
+            match control {
+                Some(Control::Pause) => {
+                    self.paused = !self.paused;
+                    if !self.paused {
+                        return Ok(ControlFlow::Continue(()));
+                    }
+                }
+                Some(Control::Step) => {
+                    self.paused = true;
+                    return Ok(ControlFlow::Continue(()));
+                }
+                Some(Control::Faster) => self.delay /= 2,
+                Some(Control::Slower) => {
+                    self.delay = (self.delay * 2).max(Duration::from_millis(1))
+                }
+                Some(Control::Quit) => {
+                    self.finish()?;
+                    return Ok(ControlFlow::Break(()));
+                }
+                // Stdin was closed: play on without controls.
+                None => {
+                    self.controls = None;
+                    self.paused = false;
+                }
+            }
+        }
+    }
+}
+
+impl<W: Write> Frames for Animation<'_, W> {
+    fn step(&mut self, draw: &dyn Fn() -> Frame) -> Result<()> {
+        if self.stopped {
+            return Ok(());
+        }
+        self.show(&draw())?;
+        self.stopped = self.wait()?.is_break();
+        Ok(())
+    }
+
+    fn end(&mut self, draw: &dyn Fn() -> Frame) -> Result<()> {
+        if self.stopped {
+            return Ok(());
+        }
+        self.show(&draw())?;
+        self.finish()
+    }
+}
+
+/// Moves the start of a window of `size` cells so that `focus` stays a
+/// quarter of the window away from its edges.
+fn scroll(start: usize, focus: usize, size: usize) -> usize {
+    let margin = size / 4;
+    if focus < start + margin {
+        focus.saturating_sub(margin)
+    } else if focus + margin >= start + size {
+        focus + margin + 1 - size
+    } else {
+        start
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Animation;
+    use super::Control;
+    use crate::frames::Frame;
+    use crate::frames::Frames;
+    use crate::grid::Grid;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    /// A 10x10 picture with each cell showing its column.
+    fn frame(focus: (usize, usize)) -> Frame {
+        let cells = (0..100)
+            .map(|i| char::from(b'0' + (i % 10) as u8))
+            .collect();
+        Frame::with_focus(Grid::from_vec(10, cells).unwrap(), focus)
+    }
+
+    #[test]
+    fn test_viewport_follows_focus() {
+        let mut animation = Animation::new(Vec::new(), Duration::ZERO, 4, 2);
+        animation.step(&|| frame((1, 0))).unwrap();
+        animation.step(&|| frame((5, 6))).unwrap();
+        animation.end(&|| frame((9, 9))).unwrap();
+        let output = String::from_utf8(animation.into_inner()).unwrap();
+        let screens = output.split("\x1b[H").skip(1).collect::<Vec<_>>();
+        assert_eq!(screens.len(), 3);
+        assert!(screens[0].starts_with("0123\x1b[K\n0123\x1b[K\nframe 1, delay 0ns\x1b[K"));
+        assert!(screens[1].starts_with("3456\x1b[K\n3456\x1b[K\nframe 2"));
+        assert!(screens[2].starts_with("6789\x1b[K\n6789\x1b[K\nframe 3"));
+        assert!(output.starts_with("\x1b[2J\x1b[?25l") && output.ends_with("\x1b[?25h"));
+    }
+
+    #[test]
+    fn test_controls() {
+        let (sender, receiver) = mpsc::channel();
+        let mut animation =
+            Animation::new(Vec::new(), Duration::from_secs(60), 4, 2).with_controls(&receiver);
+        for line in ["n", "-", "", "q"] {
+            sender.send(Control::parse(line).unwrap()).unwrap();
+        }
+        // Step pauses, then resuming returns at once instead of waiting a
+        // minute, and quitting skips the remaining frames.
+        animation.step(&|| frame((0, 0))).unwrap();
+        assert!(animation.paused);
+        animation.step(&|| frame((0, 0))).unwrap();
+        assert_eq!(
+            (animation.paused, animation.delay),
+            (false, Duration::from_secs(120))
+        );
+        animation.step(&|| frame((0, 0))).unwrap();
+        assert!(animation.stopped());
+        animation.step(&|| frame((0, 0))).unwrap();
+        animation.end(&|| frame((0, 0))).unwrap();
+        assert_eq!(animation.shown(), 3);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::days;
+use crate::input::Source;
+use crate::template::Template;
+use crate::Error;
+use crate::Part;
+use crate::Result;
+use std::fmt;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The manifest of known answers, one `<day> <input file> <part> <answer>`
+/// entry per line with input files relative to `data/`.
+pub const MANIFEST_PATH: &str = "data/answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u32,
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+/// An answer that the solver did not reproduce.
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    pub expected: Answer,
+    /// What the solver returned, or the error it failed with.
+    pub actual: std::result::Result<String, String>,
+}
+
+const ENTRY: Template = Template::new("{} {} {} {}");
+
+impl FromStr for Answer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (day, input, part, answer) = ENTRY.parse(s)?;
+        Ok(Answer {
+            day,
+            input,
+            part,
+            answer,
+        })
+    }
+}
+
+impl Answer {
+    /// The input file, relative to the working directory.
+    pub fn path(&self) -> PathBuf {
+        Path::new(crate::input::DATA_DIR).join(&self.input)
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let e = &self.expected;
+        write!(
+            f,
+            "day {} part {} on {}: expected {}",
+            e.day, e.part, e.input, e.answer
+        )?;
+        match &self.actual {
+            Ok(answer) => write!(f, ", got {}", answer),
+            Err(message) => write!(f, ", failed with {}", message),
+        }
+    }
+}
+
+/// Parses a manifest, skipping blank lines and `#` comments.
+pub fn parse(data: &str) -> Result<Vec<Answer>> {
+    data.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| Answer::from_str(line).map_err(|e| e.at_line(i + 1, line)))
+        .collect()
+}
+
+pub fn read(path: &str) -> Result<Vec<Answer>> {
+    let data = crate::read_to_string(path)?;
+    parse(&data).map_err(|e| e.in_file(path))
+}
+
+/// The recorded answer of one part of `day` on the input file at `input`.
+pub fn find<'a>(answers: &'a [Answer], day: u32, input: &str, part: Part) -> Option<&'a Answer> {
+    answers
+        .iter()
+        .find(|a| a.day == day && a.part == part && a.path() == Path::new(input))
+}
+
+/// Runs the solver of every answer in the manifest and returns the ones it
+/// does not reproduce. Each input is parsed once for all of its answers.
+pub fn check(answers: &[Answer]) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    let mut groups: Vec<(u32, &str)> = Vec::new();
+    for a in answers {
+        if !groups.contains(&(a.day, a.input.as_str())) {
+            groups.push((a.day, a.input.as_str()));
+        }
+    }
+    for (day_number, input) in groups {
+        let expected = answers
+            .iter()
+            .filter(|a| a.day == day_number && a.input == input);
+        let path = Path::new(crate::input::DATA_DIR).join(input);
+        let parsed = days::find(day_number)
+            .ok_or_else(|| format!("day {} is not registered", day_number))
+            .and_then(|day| {
+                let input = Source::File(path)
+                    .read(day_number)
+                    .map_err(|e| e.to_string())?;
+                let parsed = day
+                    .parse(&input.data, &input.params)
+                    .map_err(|e| e.in_file(&input.name).to_string())?;
+                Ok((day, parsed))
+            });
+        for answer in expected {
+            let actual = match &parsed {
+                Ok((day, parsed)) => day
+                    .solve(parsed.as_ref(), answer.part)
+                    .map_err(|e| e.to_string()),
+                Err(message) => Err(message.clone()),
+            };
+            if actual.as_ref() != Ok(&answer.answer) {
+                mismatches.push(Mismatch {
+                    expected: answer.clone(),
+                    actual,
+                });
+            }
+        }
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod test {
+    use super::find;
+    use super::parse;
+    use super::Answer;
+    use crate::Part;
+
+    #[test]
+    fn test_parse() {
+        let answers = parse("# comment\n\n5 day05.txt 2 BNTZFPMMW\n").unwrap();
+        assert_eq!(
+            answers,
+            vec![Answer {
+                day: 5,
+                input: "day05.txt".to_string(),
+                part: Part::Two,
+                answer: "BNTZFPMMW".to_string(),
+            }]
+        );
+        assert_eq!(
+            parse("5 day05.txt 3 X").unwrap_err().to_string(),
+            "line 1: column 13: expected 1 or 2, found \"3\" in \"5 day05.txt 3 X\""
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let answers = parse("5 day05.txt 1 A\n5 day05.txt 2 B\n5 other.txt 2 C\n").unwrap();
+        assert_eq!(
+            find(&answers, 5, "data/day05.txt", Part::Two)
+                .unwrap()
+                .answer,
+            "B"
+        );
+        assert_eq!(
+            find(&answers, 5, "data/other.txt", Part::Two)
+                .unwrap()
+                .answer,
+            "C"
+        );
+        assert!(find(&answers, 5, "day05.txt", Part::One).is_none());
+        assert!(find(&answers, 6, "data/day05.txt", Part::One).is_none());
+    }
+}
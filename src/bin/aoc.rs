@@ -0,0 +1,816 @@
+use aoc::animation;
+use aoc::animation::Animation;
+use aoc::animation::Control;
+use aoc::answers;
+use aoc::days;
+use aoc::frames::FrameDir;
+use aoc::generate::Rng;
+use aoc::generate::Size;
+use aoc::heap;
+use aoc::image::Format;
+use aoc::image::Palette;
+use aoc::input::Input;
+use aoc::input::Source;
+use aoc::params::Params;
+use aoc::runner;
+use aoc::runner::Bench;
+use aoc::runner::DayOutcome;
+use aoc::runner::DayRun;
+use aoc::site;
+use aoc::submit;
+use aoc::submit::Outcome;
+use aoc::Day;
+use aoc::Part;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::mpsc::Receiver;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+const USAGE: &str = "usage: aoc <day|all> [--part 1|2] [<input file>|-|--example|--text <input>]
+           [--param <name=value,...>]
+           [--time|--bench <runs>] [--csv <file>] [--format text|json]
+           [--render <dir> [--every <steps>] [--scale <pixels>] [--ppm] [--palette <c=rrggbb,...>]]
+           [--animate [--delay <ms>]] [--parallel [--threads <n>]]
+           (stdin and gzip-compressed inputs are streamed to day 6 and read whole for the others)
+       aoc new <day>
+       aoc fetch <day|all>
+       aoc validate <day|all> [<input file>|-|--example|--text <input>]
+       aoc generate <day> [--seed <n>] [--count <n>] [--extent <n>]
+           (day 10 draws on a screen of fixed size: --count and --extent have no effect)
+       aoc submit <day> <part> [<answer>]";
+
+struct Options {
+    days: Vec<&'static Day>,
+    parts: Vec<Part>,
+    source: Source,
+    /// Overrides the parameters that come with the input.
+    params: Params,
+    time: bool,
+    bench: Option<usize>,
+    csv: Option<PathBuf>,
+    visual: Option<Visual>,
+    json: bool,
+    /// Run the days concurrently on this many threads.
+    parallel: Option<usize>,
+}
+
+/// Replaying the steps of a simulation instead of only solving.
+enum Visual {
+    Render(Render),
+    /// Plays in the terminal with the given delay between frames.
+    Animate(Duration),
+}
+
+/// Where and how to write the frames of `--render`.
+struct Render {
+    dir: PathBuf,
+    every: usize,
+    scale: usize,
+    format: Format,
+    palette: Palette,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut days = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut source = None;
+    let mut params = Params::default();
+    let mut time = false;
+    let mut bench = None;
+    let mut csv = None;
+    let mut render = None;
+    let mut every = 1;
+    let mut scale = 4;
+    let mut format = Format::Png;
+    let mut palette = Palette::default();
+    let mut json = false;
+    let mut parallel = false;
+    let mut threads = None;
+    let mut animate = false;
+    let mut delay = Duration::from_millis(50);
+    let mut render_option = None;
+    let mut animate_option = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if ["--every", "--scale", "--ppm", "--palette"].contains(&arg.as_str()) {
+            render_option = Some(arg);
+        }
+        if arg == "--delay" {
+            animate_option = Some(arg);
+        }
+        match arg.as_str() {
+            "--part" => {
+                let value = iter.next().ok_or("--part needs a value")?;
+                let part = value
+                    .parse()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| format!("invalid part: {}", value))?;
+                parts = vec![part];
+            }
+            "--param" => {
+                let value = iter.next().ok_or("--param needs name=value pairs")?;
+                let value = value
+                    .parse()
+                    .map_err(|e| format!("invalid parameters: {}", e))?;
+                params = params.overridden_by(&value);
+            }
+            "--time" => time = true,
+            "--bench" => bench = Some(parse_count(iter.next(), "--bench needs a number of runs")?),
+            "--csv" => {
+                let path = iter.next().ok_or("--csv needs a file")?;
+                csv = Some(PathBuf::from(path));
+            }
+            "--parallel" => parallel = true,
+            "--threads" => threads = Some(parse_count(iter.next(), "--threads needs a number")?),
+            "--format" => {
+                json = match iter.next().map(String::as_str) {
+                    Some("text") => false,
+                    Some("json") => true,
+                    Some(other) => return Err(format!("invalid format: {}", other)),
+                    None => return Err("--format needs text or json".to_string()),
+                };
+            }
+            "--render" => {
+                let dir = iter.next().ok_or("--render needs a directory")?;
+                render = Some(PathBuf::from(dir));
+            }
+            "--every" => every = parse_count(iter.next(), "--every needs a number of steps")?,
+            "--scale" => scale = parse_count(iter.next(), "--scale needs a number of pixels")?,
+            "--ppm" => format = Format::Ppm,
+            "--palette" => {
+                let value = iter.next().ok_or("--palette needs a list of colours")?;
+                palette = value
+                    .parse()
+                    .map_err(|e| format!("invalid palette: {}", e))?;
+            }
+            "--animate" => animate = true,
+            "--delay" => {
+                let value = iter
+                    .next()
+                    .ok_or("--delay needs a number of milliseconds")?;
+                let ms = value
+                    .parse()
+                    .map_err(|_| format!("invalid delay: {}", value))?;
+                delay = Duration::from_millis(ms);
+            }
+            "--example" => set_source(&mut source, Source::Example)?,
+            "--text" => {
+                let text = iter.next().ok_or("--text needs a value")?;
+                set_source(&mut source, Source::Text(text.clone()))?;
+            }
+            day if days.is_none() => days = Some(parse_days(day)?),
+            path if !path.starts_with("--") => set_source(&mut source, Source::from_arg(path))?,
+            other => return Err(format!("unexpected argument: {}", other)),
+        }
+    }
+    let days = days.ok_or("missing day")?;
+    let source = source.unwrap_or(Source::Default);
+    if days.len() > 1 && !matches!(source, Source::Default | Source::Example) {
+        return Err("an input can only be given for a single day".to_string());
+    }
+    if time && bench.is_some() {
+        return Err("--time and --bench cannot be combined".to_string());
+    }
+    if let (None, Some(option)) = (&render, render_option) {
+        return Err(format!("{} needs --render", option));
+    }
+    if let (false, Some(option)) = (animate, animate_option) {
+        return Err(format!("{} needs --animate", option));
+    }
+    if animate && source == Source::Stdin {
+        return Err(
+            "--animate reads its controls from stdin, which cannot also be the input".to_string(),
+        );
+    }
+    if render.is_some() && animate {
+        return Err("--render and --animate cannot be combined".to_string());
+    }
+    if (render.is_some() || animate) && (time || bench.is_some() || csv.is_some()) {
+        return Err("--render and --animate cannot be combined with timing".to_string());
+    }
+    if threads.is_some() && !parallel {
+        return Err("--threads needs --parallel".to_string());
+    }
+    if parallel && (json || time || bench.is_some() || csv.is_some() || render.is_some() || animate)
+    {
+        return Err("--parallel only prints its summary table".to_string());
+    }
+    if parallel && heap::ENABLED {
+        return Err(
+            "--parallel cannot measure memory: the count-allocations counters are shared by \
+             all threads"
+                .to_string(),
+        );
+    }
+    if json && (bench.is_some() || render.is_some() || animate) {
+        return Err("--format json only reports answers".to_string());
+    }
+    let visual = match render {
+        Some(dir) => Some(Visual::Render(Render {
+            dir,
+            every,
+            scale,
+            format,
+            palette,
+        })),
+        None if animate => Some(Visual::Animate(delay)),
+        None => None,
+    };
+    Ok(Options {
+        days,
+        parts,
+        source,
+        params,
+        time: time || csv.is_some(),
+        bench,
+        csv,
+        visual,
+        json,
+        parallel: parallel.then(|| {
+            threads.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+        }),
+    })
+}
+
+fn parse_count(value: Option<&String>, missing: &str) -> Result<usize, String> {
+    let value = value.ok_or(missing)?;
+    value
+        .parse::<usize>()
+        .ok()
+        .filter(|&n| n > 0)
+        .ok_or_else(|| format!("invalid number: {}", value))
+}
+
+/// Parses `all` or the number of a solved day.
+fn parse_days(s: &str) -> Result<Vec<&'static Day>, String> {
+    if s == "all" {
+        return Ok(days::ALL.iter().collect());
+    }
+    let number = parse_day_number(s)?;
+    let day = days::find(number).ok_or_else(|| format!("day {} is not solved", number))?;
+    Ok(vec![day])
+}
+
+fn parse_day_number(s: &str) -> Result<u32, String> {
+    s.parse::<u32>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("invalid day: {}", s))
+}
+
+fn set_source(source: &mut Option<Source>, value: Source) -> Result<(), String> {
+    if source.is_some() {
+        return Err("more than one input given".to_string());
+    }
+    *source = Some(value);
+    Ok(())
+}
+
+fn print_run(run: &DayRun, time: bool) -> bool {
+    let mut ok = true;
+    for part in &run.parts {
+        match &part.answer {
+            Ok(answer) if answer.contains('\n') => println!("Part {}:\n{}", part.part, answer),
+            Ok(answer) => println!("Part {}: {}", part.part, answer),
+            Err(e) => {
+                eprintln!("Day {} part {}: {}", run.day, part.part, e);
+                ok = false;
+            }
+        }
+    }
+    if time {
+        let mut line = format!("Time: parse {:.2?}", run.parse_elapsed);
+        for part in &run.parts {
+            line += &format!(", part {} {:.2?}", part.part, part.elapsed);
+        }
+        println!("{}", line);
+    }
+    if let Some(memory) = &run.parse_memory {
+        println!("Memory: parse {}", memory);
+    }
+    for part in &run.parts {
+        if let Some(memory) = &part.memory {
+            println!("Memory: part {} {}", part.part, memory);
+        }
+    }
+    ok
+}
+
+fn print_bench(bench: &Bench) {
+    println!(
+        "{:>8} {:>6} {:>10} {:>10} {:>10}",
+        "stage", "runs", "min", "median", "max"
+    );
+    for (stage, stats) in &bench.stages {
+        println!(
+            "{:>8} {:>6} {:>10.2?} {:>10.2?} {:>10.2?}",
+            stage.to_string(),
+            stats.runs,
+            stats.min,
+            stats.median,
+            stats.max
+        );
+    }
+}
+
+/// Reads the input of a day with the parameters given by `--param`.
+fn read_input(day: &Day, options: &Options) -> aoc::Result<Input> {
+    let input = options.source.read(day.number)?;
+    Ok(input.with_params(&options.params))
+}
+
+/// Runs one day and returns its timings, or `None` if anything failed.
+fn run_day(day: &Day, options: &Options) -> Option<Bench> {
+    let result = match options.bench {
+        Some(runs) => read_input(day, options).and_then(|input| {
+            runner::bench(day, &input, &options.parts, runs).map(|bench| {
+                print_bench(&bench);
+                Some(bench)
+            })
+        }),
+        None => runner::run_source(day, &options.source, &options.params, &options.parts, false)
+            .map(|run| {
+                if print_run(&run, options.time) {
+                    Some(run.to_bench())
+                } else {
+                    None
+                }
+            }),
+    };
+    result.unwrap_or_else(|e| {
+        eprintln!("Day {}: {}", day.number, e);
+        None
+    })
+}
+
+/// Runs one day like `run_day`, printing a JSON line per part instead.
+fn run_day_json(day: &Day, options: &Options) -> Option<Bench> {
+    let params = options
+        .source
+        .params(day.number)
+        .overridden_by(&options.params);
+    // A streamed input is never held whole, so its failures cannot name it.
+    let (result, input) = match options.source.open(day.number) {
+        Ok(reader) if reader.is_large() => {
+            let result = runner::run_reader(day, reader, params, &options.parts, true);
+            (result, None)
+        }
+        Ok(reader) => match Input::read(reader, params) {
+            Ok(input) => (runner::run(day, &input, &options.parts), Some(input)),
+            Err(e) => (Err(e), None),
+        },
+        Err(e) => (Err(e), None),
+    };
+    match result {
+        Ok(run) => {
+            print!("{}", run.to_json());
+            run.parts
+                .iter()
+                .all(|p| p.answer.is_ok())
+                .then(|| run.to_bench())
+        }
+        Err(e) => {
+            print!(
+                "{}",
+                runner::failure_json(day.number, &options.parts, input.as_ref(), &e)
+            );
+            None
+        }
+    }
+}
+
+/// How replaying the parts of a day ended.
+enum Replay {
+    Done,
+    Failed,
+    /// Quit from the animation controls: the whole run ends, successfully.
+    Stopped,
+}
+
+/// Replays each part of one day, writing its frames into
+/// `<dir>/dayNN-partN` or playing them in the terminal.
+fn visualise_day(
+    day: &Day,
+    options: &Options,
+    visual: &Visual,
+    controls: Option<&Receiver<Control>>,
+) -> Replay {
+    let input = match read_input(day, options) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Day {}: {}", day.number, e);
+            return Replay::Failed;
+        }
+    };
+    let mut replay = Replay::Done;
+    for &part in &options.parts {
+        let mut stopped = false;
+        let result = match visual {
+            Visual::Render(render) => {
+                let dir = render
+                    .dir
+                    .join(format!("day{:02}-part{}", day.number, part));
+                let mut frames = FrameDir::new(
+                    dir.clone(),
+                    render.format,
+                    render.palette.clone(),
+                    render.scale,
+                    render.every,
+                );
+                runner::visualise(day, &input, part, &mut frames)
+                    .map(|()| format!("{} frames in {}", frames.written(), dir.display()))
+            }
+            Visual::Animate(delay) => {
+                let (width, height) = terminal_size();
+                let mut frames = Animation::new(io::stdout(), *delay, width, height);
+                if let Some(controls) = controls {
+                    frames = frames.with_controls(controls);
+                }
+                let result = runner::visualise(day, &input, part, &mut frames)
+                    .map(|()| format!("{} frames shown", frames.shown()));
+                stopped = frames.stopped();
+                result
+            }
+        };
+        if stopped {
+            return Replay::Stopped;
+        }
+        match result {
+            Ok(summary) => println!("Part {}: {}", part, summary),
+            Err(e) => {
+                eprintln!("Day {} part {}: {}", day.number, part, e);
+                replay = Replay::Failed;
+            }
+        }
+    }
+    replay
+}
+
+/// The viewport for animations: the terminal size from `COLUMNS` and
+/// `LINES` if the shell exports them, less a line for the status.
+fn terminal_size() -> (usize, usize) {
+    let get = |name, default| {
+        env::var(name)
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(default)
+    };
+    (get("COLUMNS", 80), get("LINES", 24).saturating_sub(1))
+}
+
+/// Creates the solver skeleton and input files of a new day.
+fn new_day(args: &[String]) -> Result<ExitCode, String> {
+    let day = match args {
+        [day] => parse_day_number(day)?,
+        _ => return Err("new needs a single day".to_string()),
+    };
+    match aoc::scaffold::create(Path::new(""), day) {
+        Ok(created) => {
+            for path in created {
+                println!("{}: created", path.display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+/// Downloads the inputs that are not in `data/` yet.
+fn fetch(args: &[String]) -> Result<ExitCode, String> {
+    let numbers = match args {
+        [all] if all == "all" => days::ALL.iter().map(|d| d.number).collect(),
+        [day] => vec![parse_day_number(day)?],
+        _ => return Err("fetch needs a single day or all".to_string()),
+    };
+    let client = match site::Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let mut failed = false;
+    for day in numbers {
+        match site::fetch_input_to(&client, day, Path::new(aoc::input::DATA_DIR)) {
+            Ok((path, true)) => println!("{}: downloaded", path.display()),
+            Ok((path, false)) => println!("{}: already present", path.display()),
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                failed = true;
+            }
+        }
+    }
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Submits an answer, computing it from the day's input when not given.
+fn submit(args: &[String]) -> Result<ExitCode, String> {
+    let (day, part, answer) = match args {
+        [day, part] => (day, part, None),
+        [day, part, answer] => (day, part, Some(answer.clone())),
+        _ => return Err("submit needs a day, a part and optionally an answer".to_string()),
+    };
+    let day = parse_day_number(day)?;
+    let part = part
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("invalid part: {}", part))?;
+    let result = match answer {
+        Some(answer) => Ok(answer),
+        None => solve(day, part),
+    }
+    .and_then(|answer| {
+        let client = site::Client::from_env()?;
+        let mut log = submit::Log::open(Path::new(submit::LOG_PATH))?;
+        println!("Day {} part {}: submitting {}", day, part, answer);
+        submit::submit(&client, &mut log, day, part, &answer)
+    });
+    match result {
+        Ok(outcome) => {
+            println!("{}", outcome);
+            Ok(if outcome == Outcome::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Err(e) => {
+            eprintln!("Day {} part {}: {}", day, part, e);
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+/// Checks the shape of inputs without solving them, reporting every
+/// violation found.
+fn validate(args: &[String]) -> Result<ExitCode, String> {
+    let mut days = None;
+    let mut source = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--example" => set_source(&mut source, Source::Example)?,
+            "--text" => {
+                let text = iter.next().ok_or("--text needs a value")?;
+                set_source(&mut source, Source::Text(text.clone()))?;
+            }
+            day if days.is_none() => days = Some(parse_days(day)?),
+            path if !path.starts_with("--") => set_source(&mut source, Source::from_arg(path))?,
+            other => return Err(format!("unexpected argument: {}", other)),
+        }
+    }
+    let days = days.ok_or("validate needs a day or all")?;
+    let source = source.unwrap_or(Source::Default);
+    if days.len() > 1 && !matches!(source, Source::Default | Source::Example) {
+        return Err("an input can only be given for a single day".to_string());
+    }
+    let mut failed = false;
+    for day in days {
+        let input = match source.read(day.number) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {}: {}", day.number, e);
+                failed = true;
+                continue;
+            }
+        };
+        let errors = day.validate(&input.data, &source);
+        if errors.is_empty() {
+            println!("Day {}: {}: ok", day.number, input.name);
+            continue;
+        }
+        failed = true;
+        let plural = if errors.len() == 1 { "" } else { "s" };
+        eprintln!("Day {}: {} violation{}", day.number, errors.len(), plural);
+        for e in errors {
+            eprintln!("  {}", e.in_file(&input.name));
+        }
+    }
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Prints a random input for a day, the same for the same seed and size.
+fn generate(args: &[String]) -> Result<ExitCode, String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = Size::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = iter.next().ok_or("--seed needs a number")?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid seed: {}", value))?;
+            }
+            "--count" => size.count = parse_count(iter.next(), "--count needs a number")?,
+            "--extent" => size.extent = parse_count(iter.next(), "--extent needs a number")?,
+            number if day.is_none() => {
+                let number = parse_day_number(number)?;
+                day = Some(
+                    days::find(number).ok_or_else(|| format!("day {} is not solved", number))?,
+                );
+            }
+            other => return Err(format!("unexpected argument: {}", other)),
+        }
+    }
+    let day = day.ok_or("generate needs a day")?;
+    match day.generate(&mut Rng::new(seed), size) {
+        Ok(input) => {
+            print!("{}", input.data);
+            if !input.params.is_empty() {
+                eprintln!("Day {}: solve with --param {}", day.number, input.params);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", day.number, e);
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+fn solve(day: u32, part: Part) -> aoc::Result<String> {
+    let solver = days::find(day)
+        .ok_or_else(|| aoc::Error::structure(format!("day {} is not solved", day)))?;
+    let input = Source::Default.read(day)?;
+    let mut run = runner::run(solver, &input, &[part])?;
+    run.parts.remove(0).answer
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.first().map(String::as_str) {
+        Some("new") => new_day(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("validate") => validate(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        _ => parse_args(&args).map(|options| run(&options)),
+    };
+    result.unwrap_or_else(|message| {
+        eprintln!("{}\n{}", message, USAGE);
+        ExitCode::from(2)
+    })
+}
+
+fn run(options: &Options) -> ExitCode {
+    runner::install_panic_hook();
+    if let Some(threads) = options.parallel {
+        return run_parallel(options, threads);
+    }
+    let mut failed = false;
+    let mut csv = format!("{}\n", runner::CSV_HEADER);
+    let controls =
+        matches!(options.visual, Some(Visual::Animate(_))).then(animation::stdin_controls);
+    for day in &options.days {
+        if options.json {
+            match run_day_json(day, options) {
+                Some(bench) => csv += &bench.to_csv(),
+                None => failed = true,
+            }
+            continue;
+        }
+        println!("Day {}", day.number);
+        if let Some(visual) = &options.visual {
+            match visualise_day(day, options, visual, controls.as_ref()) {
+                Replay::Done => (),
+                Replay::Failed => failed = true,
+                Replay::Stopped => break,
+            }
+            continue;
+        }
+        match run_day(day, options) {
+            Some(bench) => csv += &bench.to_csv(),
+            None => failed = true,
+        }
+    }
+    if let Some(path) = &options.csv {
+        if let Err(e) = fs::write(path, csv) {
+            eprintln!("{}: {}", path.display(), e);
+            failed = true;
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Runs the days concurrently and prints a table of their answers, times
+/// and status, checking the answers recorded in the manifest. Problems are
+/// detailed after the table.
+fn run_parallel(options: &Options, threads: usize) -> ExitCode {
+    let recorded = answers::read(answers::MANIFEST_PATH).unwrap_or_else(|e| {
+        eprintln!("{}; answers are not checked", e);
+        Vec::new()
+    });
+    let start = Instant::now();
+    let reports = runner::run_parallel(
+        &options.days,
+        &options.source,
+        &options.params,
+        &options.parts,
+        threads,
+    );
+    let total = start.elapsed();
+
+    let mut problems = Vec::new();
+    let mut rows = Vec::new();
+    for report in &reports {
+        let mut row = vec![report.day.to_string()];
+        let status = match &report.outcome {
+            DayOutcome::Ran(run) => {
+                let (mut panicked, mut failed) = (false, false);
+                let (mut wrong, mut unchecked) = (false, false);
+                for part in &run.parts {
+                    let expected = answers::find(&recorded, run.day, &run.input, part.part);
+                    match (&part.answer, expected) {
+                        (Ok(answer), Some(expected)) if *answer != expected.answer => {
+                            problems.push(format!(
+                                "Day {} part {}: expected {}, got {}",
+                                run.day, part.part, expected.answer, answer
+                            ));
+                            wrong = true;
+                        }
+                        (Ok(_), expected) => unchecked |= expected.is_none(),
+                        (Err(e), _) => {
+                            problems.push(format!("Day {} part {}: {}", run.day, part.part, e));
+                            match e {
+                                aoc::Error::Panicked { .. } => panicked = true,
+                                _ => failed = true,
+                            }
+                        }
+                    }
+                    row.push(part.answer.as_ref().map_or("-".to_string(), |a| a.clone()));
+                }
+                match (panicked, failed, wrong, unchecked) {
+                    (true, _, _, _) => "panic",
+                    (_, true, _, _) => "error",
+                    (_, _, true, _) => "wrong",
+                    (_, _, _, true) => "unchecked",
+                    _ => "ok",
+                }
+            }
+            DayOutcome::Failed(e) => {
+                problems.push(format!("Day {}: {}", report.day, e));
+                row.extend(options.parts.iter().map(|_| "-".to_string()));
+                "error"
+            }
+            DayOutcome::Panicked(e) => {
+                problems.push(format!("Day {}: {}", report.day, e));
+                row.extend(options.parts.iter().map(|_| "-".to_string()));
+                "panic"
+            }
+        };
+        row.push(format!("{:.2?}", report.elapsed));
+        row.push(status.to_string());
+        rows.push(row);
+    }
+
+    let part_headers = options
+        .parts
+        .iter()
+        .map(|p| format!("part {}", p))
+        .collect::<Vec<_>>();
+    let mut header = vec!["day"];
+    header.extend(part_headers.iter().map(String::as_str));
+    header.extend(["time", "status"]);
+    print!("{}", runner::format_table(&header, &rows));
+    let plural = if threads == 1 { "" } else { "s" };
+    println!(
+        "Total: {} days in {:.2?} on {} thread{}",
+        reports.len(),
+        total,
+        threads,
+        plural
+    );
+    for problem in &problems {
+        eprintln!("{}", problem);
+    }
+    if problems.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,72 @@
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::validate;
+use crate::Error;
+use crate::Solution;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct Backpack {
+    items: Vec<u32>,
+}
+
+impl FromStr for Backpack {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Backpack {
+            items: crate::parse_lines(s)?,
+        })
+    }
+}
+
+fn sum_top_backpacks(backpacks: &[Backpack], n: usize) -> u32 {
+    let mut sums = backpacks
+        .iter()
+        .map(|b| b.items.iter().sum())
+        .collect::<Vec<u32>>();
+    sums.sort_by(|a, b| b.cmp(a));
+    sums.iter().take(n).sum::<u32>()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Backpack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_groups(data)
+    }
+
+    /// Groups of numbers separated by blank lines.
+    fn validate(data: &str) -> Vec<Error> {
+        validate::check_lines(1, data.lines(), |line| {
+            if line.trim().is_empty() {
+                Vec::new()
+            } else {
+                validate::parses::<u32>(line)
+            }
+        })
+    }
+
+    /// `count` elves carrying up to `extent` snacks each.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let mut backpacks = Vec::new();
+        for _ in 0..size.count.max(1) {
+            let snacks = 1 + rng.below(size.extent.max(1));
+            let calories = (0..snacks).map(|_| format!("{}\n", rng.range(1000..=60000)));
+            backpacks.push(calories.collect::<String>());
+        }
+        Ok(backpacks.join("\n"))
+    }
+
+    fn part1(backpacks: &Self::Input) -> crate::Result<u32> {
+        Ok(sum_top_backpacks(backpacks, 1))
+    }
+
+    fn part2(backpacks: &Self::Input) -> crate::Result<u32> {
+        Ok(sum_top_backpacks(backpacks, 3))
+    }
+}
@@ -1,3 +1,9 @@
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::template::Template;
+use crate::validate;
+use crate::Error;
+use crate::Solution;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -22,20 +28,20 @@ struct Round {
 }
 
 #[derive(Debug)]
-struct Guide {
+pub struct Guide {
     shape: Shape,
     strategy: Strategy,
 }
 
 impl FromStr for Strategy {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
             "X" => Strategy::X,
             "Y" => Strategy::Y,
             "Z" => Strategy::Z,
-            _ => return Err("Cannot parse strategy"),
+            _ => return Err(Error::unexpected(1, s, "X, Y or Z")),
         })
     }
 }
@@ -81,31 +87,28 @@ impl Round {
 }
 
 impl FromStr for Shape {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
             "A" => Shape::Rock,
             "B" => Shape::Paper,
             "C" => Shape::Scissors,
-            _ => return Err("Cannot parse Shape"),
+            _ => return Err(Error::unexpected(1, s, "A, B or C")),
         })
     }
 }
 
 impl FromStr for Guide {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (a, b) = s.split_once(" ").ok_or("Cannot parse guide")?;
-        Ok(Guide {
-            shape: Shape::from_str(a)?,
-            strategy: Strategy::from_str(b)?,
-        })
+        let (shape, strategy) = Template::new("{} {}").parse(s)?;
+        Ok(Guide { shape, strategy })
     }
 }
 
-fn get_total_score<F>(guides: &Vec<Guide>, action: F) -> u32
+fn get_total_score<F>(guides: &[Guide], action: F) -> u32
 where
     F: Fn(&Guide) -> Shape,
 {
@@ -121,37 +124,58 @@ where
         .sum::<u32>()
 }
 
-fn main() {
-    let guides = aoc::read_one_per_line::<Guide>("data/day02.txt");
-    println!(
-        "Part 1: {}",
-        get_total_score(&guides, |g| {
-            match g.strategy {
-                Strategy::X => Shape::Rock,
-                Strategy::Y => Shape::Paper,
-                Strategy::Z => Shape::Scissors,
-            }
-        })
-    );
-    println!(
-        "Part 2: {}",
-        get_total_score(&guides, |g| {
-            match g.strategy {
-                Strategy::X => g.shape.get_victim(),
-                Strategy::Y => g.shape,
-                Strategy::Z => g.shape.get_victor(),
-            }
-        })
-    );
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Guide>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn validate(data: &str) -> Vec<Error> {
+        validate::check_lines(1, data.lines(), validate::parses::<Guide>)
+    }
+
+    /// `count` rounds.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let mut data = String::new();
+        for _ in 0..size.count.max(1) {
+            data += &format!(
+                "{} {}\n",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            );
+        }
+        Ok(data)
+    }
+
+    fn part1(guides: &Self::Input) -> crate::Result<u32> {
+        Ok(get_total_score(guides, |g| match g.strategy {
+            Strategy::X => Shape::Rock,
+            Strategy::Y => Shape::Paper,
+            Strategy::Z => Shape::Scissors,
+        }))
+    }
+
+    fn part2(guides: &Self::Input) -> crate::Result<u32> {
+        Ok(get_total_score(guides, |g| match g.strategy {
+            Strategy::X => g.shape.get_victim(),
+            Strategy::Y => g.shape,
+            Strategy::Z => g.shape.get_victor(),
+        }))
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::Shape;
+    use super::Shape;
 
     #[test]
     fn test() {
-        assert_eq!(Shape::beats(Shape::Paper, Shape::Rock), true);
-        assert_eq!(Shape::beats(Shape::Paper, Shape::Scissors), false);
+        assert!(Shape::beats(Shape::Paper, Shape::Rock));
+        assert!(!Shape::beats(Shape::Paper, Shape::Scissors));
     }
 }
@@ -0,0 +1,171 @@
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::validate;
+use crate::Error;
+use crate::Solution;
+use std::collections::HashSet;
+
+fn get_priority(c: u8) -> crate::Result<u32> {
+    match c {
+        b'a'..=b'z' => Ok((c - b'a' + 1) as u32),
+        b'A'..=b'Z' => Ok((c - b'A' + 27) as u32),
+        _ => Err(Error::structure(format!(
+            "item {:?} has no priority",
+            c as char
+        ))),
+    }
+}
+
+fn get_duplicated(s: &[u8]) -> Option<u8> {
+    let half_len = s.len() / 2;
+    let mut set: HashSet<u8> = HashSet::new();
+    for c in &s[..half_len] {
+        set.insert(*c);
+    }
+    for c in &s[half_len..] {
+        if set.contains(c) {
+            return Some(*c);
+        }
+    }
+    None
+}
+
+fn get_common_item(backpaks: &[String]) -> Option<u8> {
+    let intersection = backpaks
+        .iter()
+        .map(|s| {
+            let mut set = HashSet::new();
+            for b in s.as_bytes() {
+                set.insert(*b);
+            }
+            set
+        })
+        .reduce(|acc, s| acc.intersection(&s).copied().collect());
+    intersection.and_then(|it| it.iter().next().copied())
+}
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Three rucksacks that have only their badge in common, each with a
+/// single item in both compartments and compartments of 2 to `max_half`
+/// items.
+fn generate_group(rng: &mut Rng, max_half: usize) -> [String; 3] {
+    let mut others = ITEMS.chars().collect::<Vec<_>>();
+    let badge = others.remove(rng.below(others.len()));
+    rng.shuffle(&mut others);
+    // Each rucksack leaves out a different third of the other items, so
+    // that none of them ends up in all three.
+    let thirds = others.chunks(others.len() / 3).collect::<Vec<_>>();
+    std::array::from_fn(|i| {
+        let mut allowed = thirds
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .flat_map(|(_, third)| third.iter().copied())
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut allowed);
+        let duplicated = allowed.pop().unwrap();
+        let (left_only, right_only) = allowed.split_at(allowed.len() / 2);
+        let half = 2 + rng.below(max_half - 1);
+        let mut left = vec![duplicated, badge];
+        left.extend((2..half).map(|_| *rng.pick(left_only)));
+        let mut right = vec![duplicated];
+        right.extend((1..half).map(|_| *rng.pick(right_only)));
+        rng.shuffle(&mut left);
+        rng.shuffle(&mut right);
+        left.into_iter().chain(right).collect()
+    })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    /// Lines of letters that split into two equal compartments, in groups
+    /// of three for the badges.
+    fn validate(data: &str) -> Vec<Error> {
+        let mut errors = validate::check_lines(1, data.lines(), |line| {
+            let mut errors = validate::chars(line, "a letter", |c| c.is_ascii_alphabetic());
+            if !line.len().is_multiple_of(2) {
+                let message = format!("expected an even number of items, found {}", line.len());
+                errors.push(Error::structure(message));
+            }
+            errors
+        });
+        let count = data.lines().count();
+        if !count.is_multiple_of(3) {
+            let message = format!("expected groups of 3 rucksacks, found {} rucksacks", count);
+            errors.push(Error::structure(message));
+        }
+        errors
+    }
+
+    /// `count` groups of three rucksacks with compartments of up to
+    /// `extent` items.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let mut data = String::new();
+        for _ in 0..size.count.max(1) {
+            for rucksack in generate_group(rng, size.extent.max(2)) {
+                data += &rucksack;
+                data.push('\n');
+            }
+        }
+        Ok(data)
+    }
+
+    fn part1(backpaks: &Self::Input) -> crate::Result<u32> {
+        let sum = backpaks
+            .iter()
+            .filter_map(|b| get_duplicated(b.as_bytes()).map(get_priority))
+            .sum::<crate::Result<u32>>()?;
+        Ok(sum)
+    }
+
+    fn part2(backpaks: &Self::Input) -> crate::Result<u32> {
+        let sum = backpaks
+            .chunks(3)
+            .filter_map(get_common_item)
+            .map(get_priority)
+            .sum::<crate::Result<u32>>()?;
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    #[test]
+    fn test() {
+        let s = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let bytes = s.as_bytes();
+        assert_eq!(s.len(), bytes.len(), "Should be equal length");
+        let dup = super::get_duplicated(s.as_bytes());
+        assert_eq!(dup, Some(b'p'), "Should be correct symbol");
+    }
+
+    #[test]
+    fn badge() {
+        let backpaks = [
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn".to_string(),
+            "ttgJtRGJQctTZtZT".to_string(),
+            "CrZsJsPPZsGzwwsLwLmpwMDw".to_string(),
+        ];
+        let common_item = super::get_common_item(&backpaks[..]);
+        assert_eq!(common_item, Some(b'Z'));
+    }
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(super::get_priority(b'a').unwrap(), 1);
+        assert_eq!(super::get_priority(b'Z').unwrap(), 52);
+        let err = super::get_priority(b'1').unwrap_err();
+        assert_eq!(err.to_string(), "item '1' has no priority");
+    }
+}
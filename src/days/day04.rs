@@ -0,0 +1,143 @@
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::interval::Interval;
+use crate::template::Captures;
+use crate::template::Template;
+use crate::validate;
+use crate::Error;
+use crate::Solution;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct Pair {
+    a: Interval,
+    b: Interval,
+}
+
+const PAIR: Template = Template::new("{}-{},{}-{}");
+
+/// Parses captures `i` and `i + 1` as the first and last sections of an
+/// assignment, which must not run backwards.
+fn assignment(captures: &Captures, i: usize) -> Result<Interval, Error> {
+    let first = captures.parse(i)?;
+    let last = captures.parse(i + 1)?;
+    if last < first {
+        let expected = format!("a section not less than {}", first);
+        return Err(Error::unexpected_in(
+            captures.line(),
+            captures.get(i + 1),
+            &expected,
+        ));
+    }
+    Ok(Interval::inclusive(first, last))
+}
+
+impl FromStr for Pair {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let captures = PAIR.captures(s)?;
+        Ok(Pair {
+            a: assignment(&captures, 0)?,
+            b: assignment(&captures, 2)?,
+        })
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn validate(data: &str) -> Vec<Error> {
+        validate::check_lines(1, data.lines(), validate::parses::<Pair>)
+    }
+
+    /// `count` pairs of sections numbered up to `extent`.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let max = size.extent.max(1) as i64;
+        let mut assignment = || {
+            let (a, b) = (rng.range(1..=max), rng.range(1..=max));
+            format!("{}-{}", a.min(b), a.max(b))
+        };
+        let mut data = String::new();
+        for _ in 0..size.count.max(1) {
+            data += &format!("{},{}\n", assignment(), assignment());
+        }
+        Ok(data)
+    }
+
+    fn part1(pairs: &Self::Input) -> crate::Result<usize> {
+        let count = pairs
+            .iter()
+            .filter(|p| p.a.contains_interval(&p.b) || p.b.contains_interval(&p.a))
+            .count();
+        Ok(count)
+    }
+
+    fn part2(pairs: &Self::Input) -> crate::Result<usize> {
+        let count = pairs.iter().filter(|p| p.a.overlaps(&p.b)).count();
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day04;
+    use super::Pair;
+    use crate::interval::Interval;
+    use crate::Error;
+    use crate::Solution;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_pair_error() {
+        let err = Pair::from_str("4-8,x-9").unwrap_err();
+        assert_eq!(err.to_string(), "column 5: expected a number, found \"x\"");
+    }
+
+    #[test]
+    fn test_reversed_assignment() {
+        let err = Pair::from_str("8-2,3-4").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "column 3: expected a section not less than 8, found \"2\""
+        );
+        let err = Pair::from_str("1-2,4-3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "column 7: expected a section not less than 4, found \"3\""
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Day04::validate("2-4,6-8\n").is_empty());
+        let errors = Day04::validate("2-4,6-8\n8-2,3-4\n");
+        assert_eq!(
+            errors.iter().map(Error::to_string).collect::<Vec<_>>(),
+            vec![
+                "line 2: column 3: expected a section not less than 8, \
+                  found \"2\" in \"8-2,3-4\""
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pair_from_str() {
+        let p = Pair::from_str("1-4,2-8").unwrap();
+        assert_eq!(
+            p,
+            Pair {
+                a: Interval::inclusive(1, 4),
+                b: Interval::inclusive(2, 8),
+            }
+        );
+    }
+}
@@ -0,0 +1,465 @@
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::template::Captures;
+use crate::template::Template;
+use crate::validate;
+use crate::Error;
+use crate::Group;
+use crate::Solution;
+use std::str::Chars;
+
+pub struct Supplies {
+    stacks: Vec<Vec<char>>,
+    commands: Vec<Command>,
+}
+
+#[derive(Debug)]
+struct Command {
+    amount: u32,
+    from: usize,
+    to: usize,
+}
+
+struct BoxesSlice<'a> {
+    chars: Chars<'a>,
+}
+
+impl<'a> BoxesSlice<'a> {
+    fn new(s: &'a str) -> Self {
+        BoxesSlice { chars: s.chars() }
+    }
+}
+
+impl<'a> Iterator for BoxesSlice<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.chars.next().is_some() {
+            let result = self.chars.next();
+            self.chars.next(); // closing bracket ]
+            self.chars.next(); // space separator
+            result
+        } else {
+            None
+        }
+    }
+}
+
+const COMMAND: Template = Template::new("move {} from {} to {}");
+
+/// Parses capture `i` as the 1-based number of one of `stacks` stacks and
+/// returns its index.
+fn stack_index(captures: &Captures, i: usize, stacks: usize) -> Result<usize, Error> {
+    match captures.parse::<usize>(i)? {
+        number @ 1.. if number <= stacks => Ok(number - 1),
+        _ => Err(Error::unexpected_in(
+            captures.line(),
+            captures.get(i),
+            &format!("a stack number from 1 to {}", stacks),
+        )),
+    }
+}
+
+impl Command {
+    /// Parses a command moving crates between `stacks` stacks.
+    fn parse(line: &str, stacks: usize) -> Result<Self, Error> {
+        let captures = COMMAND.captures(line)?;
+        Ok(Command {
+            amount: captures.parse(0)?,
+            from: stack_index(&captures, 1, stacks)?,
+            to: stack_index(&captures, 2, stacks)?,
+        })
+    }
+}
+
+/// Builds the stacks of the drawing, whose first line is `first_line`.
+fn create_stacks(stacks_data: &[&str], first_line: usize) -> crate::Result<Vec<Vec<char>>> {
+    let mut result = Vec::new();
+    let mut iter = stacks_data.iter().enumerate().rev();
+    let (_, numbers_line) = iter
+        .next()
+        .ok_or_else(|| Error::structure("missing crate drawing"))?;
+    for _ in numbers_line.split_whitespace() {
+        result.push(Vec::<char>::new());
+    }
+    for (row, slice_line) in iter {
+        let boxes_slice = BoxesSlice::new(slice_line);
+        for (i, ch) in boxes_slice.enumerate() {
+            if ch != ' ' {
+                let count = result.len();
+                result
+                    .get_mut(i)
+                    .ok_or_else(|| {
+                        Error::structure(format!("crate past stack {}", count))
+                            .at_line(first_line + row, slice_line)
+                    })?
+                    .push(ch);
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Carries out `command`, moving the crates one at a time, or all at once
+/// if `keep_order`.
+fn move_crates(stacks: &mut [Vec<char>], command: &Command, keep_order: bool) -> crate::Result<()> {
+    let from = &mut stacks[command.from];
+    let amount = command.amount as usize;
+    if amount > from.len() {
+        return Err(Error::structure(format!(
+            "cannot move {} crates off stack {}, which holds {}",
+            amount,
+            command.from + 1,
+            from.len()
+        )));
+    }
+    let mut moved = from.split_off(from.len() - amount);
+    if !keep_order {
+        moved.reverse();
+    }
+    stacks[command.to].extend(moved);
+    Ok(())
+}
+
+/// Checks the crate drawing: stack numbers 1 to N in the bottom row, each
+/// centred under its column, and above them rows of `[X]` crates or blank
+/// slots, one per stack, with no crate over an empty slot. Returns the
+/// number of stacks with the violations.
+fn check_drawing(drawing: Group) -> (usize, Vec<Error>) {
+    let mut errors = Vec::new();
+    let rows = drawing.lines().collect::<Vec<_>>();
+    let numbers_row = drawing.first_line + rows.len() - 1;
+    let numbers = rows.last().copied().unwrap_or("");
+    let mut stacks = 0;
+    for token in numbers.split_whitespace() {
+        stacks += 1;
+        let column = crate::slice_offset(numbers, token);
+        if token != stacks.to_string() || column != 4 * (stacks - 1) + 1 {
+            let expected = format!("stack number {} at column {}", stacks, 4 * stacks - 2);
+            errors.push(
+                Error::unexpected_in(numbers, token, &expected).at_line(numbers_row, numbers),
+            );
+        }
+    }
+    if stacks == 0 {
+        errors.push(Error::structure("missing stack numbers").at_line(numbers_row, numbers));
+    }
+    let mut filled = vec![true; stacks];
+    for (i, row) in rows.iter().enumerate().rev().skip(1) {
+        let line = drawing.first_line + i;
+        if !row.is_ascii() {
+            let non_ascii = validate::chars(row, "a crate or a space", |c| c.is_ascii());
+            errors.extend(non_ascii.into_iter().map(|e| e.at_line(line, row)));
+            continue;
+        }
+        for (k, start) in (0..row.len()).step_by(4).enumerate() {
+            let slot = &row[start..(start + 3).min(row.len())];
+            let is_crate = slot.len() == 3
+                && slot.starts_with('[')
+                && slot.ends_with(']')
+                && slot.as_bytes()[1].is_ascii_uppercase();
+            let error = if slot.trim().is_empty() {
+                if k < stacks {
+                    filled[k] = false;
+                }
+                None
+            } else if k >= stacks {
+                Some(Error::unexpected_in(
+                    row,
+                    slot,
+                    &format!("no crate past stack {}", stacks),
+                ))
+            } else if !is_crate {
+                Some(Error::unexpected_in(
+                    row,
+                    slot,
+                    "a crate like \"[A]\" or spaces",
+                ))
+            } else if !filled[k] {
+                Some(Error::unexpected_in(row, slot, "spaces over an empty slot"))
+            } else {
+                None
+            };
+            errors.extend(error.map(|e| e.at_line(line, row)));
+            if let Some(separator) = row.get(start + 3..start + 4).filter(|&s| s != " ") {
+                errors.push(Error::unexpected_in(row, separator, "a space").at_line(line, row));
+            }
+        }
+    }
+    // The rows were checked from the bottom up, as the stacks grow.
+    validate::sort(&mut errors);
+    (stacks, errors)
+}
+
+/// Checks a command of the moves section against the number of stacks.
+fn check_command(line: &str, stacks: usize) -> Vec<Error> {
+    let captures = match COMMAND.captures(line) {
+        Ok(captures) => captures,
+        Err(e) => return vec![e],
+    };
+    let mut errors = Vec::new();
+    errors.extend(captures.parse::<u32>(0).err());
+    for i in 1..=2 {
+        errors.extend(stack_index(&captures, i, stacks).err());
+    }
+    errors
+}
+
+/// Draws `stacks` the way the puzzle does, bottom crates first in each
+/// stack, with the row of stack numbers underneath.
+fn draw_stacks(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let slots = stacks
+            .iter()
+            .map(|stack| {
+                stack
+                    .get(level)
+                    .map_or("   ".to_string(), |c| format!("[{}]", c))
+            })
+            .collect::<Vec<_>>();
+        drawing += &slots.join(" ");
+        drawing.push('\n');
+    }
+    let numbers = (1..=stacks.len())
+        .map(|i| format!(" {} ", i))
+        .collect::<Vec<_>>();
+    drawing += &numbers.join(" ");
+    drawing.push('\n');
+    drawing
+}
+
+/// Replays the moves of an otherwise valid input, reporting the first one
+/// that takes more crates than its stack holds, or else every stack that
+/// ends up empty. Both parts move the same number of crates, so one replay
+/// covers them.
+fn check_crate_counts(data: &str, moves: &Group) -> Vec<Error> {
+    let supplies = match Day05::parse(data) {
+        Ok(supplies) => supplies,
+        Err(e) => return vec![e],
+    };
+    let mut stacks = supplies.stacks;
+    for ((i, line), command) in moves.lines().enumerate().zip(&supplies.commands) {
+        if let Err(e) = move_crates(&mut stacks, command, true) {
+            return vec![e.at_line(moves.first_line + i, line)];
+        }
+    }
+    stacks
+        .iter()
+        .enumerate()
+        .filter(|(_, stack)| stack.is_empty())
+        .map(|(i, _)| Error::structure(format!("stack {} ends up empty", i + 1)))
+        .collect()
+}
+
+fn get_stacks_tops(stacks: &[Vec<char>]) -> crate::Result<String> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            s.last()
+                .ok_or_else(|| Error::structure(format!("stack {} ends up empty", i + 1)))
+        })
+        .collect()
+}
+
+fn rearrange(supplies: &Supplies, keep_order: bool) -> crate::Result<String> {
+    let mut stacks = supplies.stacks.clone();
+    for c in &supplies.commands {
+        move_crates(&mut stacks, c, keep_order)?;
+    }
+    get_stacks_tops(&stacks)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Supplies;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        let (drawing, moves) = crate::sections(data)?;
+        let stacks_data = drawing.lines().collect::<Vec<_>>();
+        let stacks = create_stacks(&stacks_data, drawing.first_line)?;
+        let commands = moves
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                Command::parse(line, stacks.len())
+                    .map_err(|e| e.at_line(moves.first_line + i, line))
+            })
+            .collect::<crate::Result<_>>()?;
+        Ok(Supplies { stacks, commands })
+    }
+
+    fn validate(data: &str) -> Vec<Error> {
+        let (drawing, moves) = match crate::sections(data) {
+            Ok(sections) => sections,
+            Err(e) => return vec![e],
+        };
+        let (stacks, mut errors) = check_drawing(drawing);
+        if moves.text.is_empty() {
+            errors.push(Error::structure("missing moves after the crate drawing"));
+        }
+        errors.extend(validate::check_lines(
+            moves.first_line,
+            moves.lines(),
+            |line| check_command(line, stacks),
+        ));
+        if errors.is_empty() {
+            errors.extend(check_crate_counts(data, &moves));
+        }
+        errors
+    }
+
+    /// `extent` stacks, between 2 and 9 so that their numbers fit the
+    /// drawing, and `count` moves, none of which empties a stack.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let count = size.extent.clamp(2, 9);
+        let crate_letter = |rng: &mut Rng| (b'A' + rng.below(26) as u8) as char;
+        let mut stacks = (0..count)
+            .map(|_| {
+                (0..1 + rng.below(8))
+                    .map(|_| crate_letter(rng))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        if stacks[0].len() < 2 {
+            stacks[0].push(crate_letter(rng));
+        }
+        let mut data = draw_stacks(&stacks);
+        data.push('\n');
+        // Every stack keeps at least one crate and there are more crates
+        // than stacks, so some stack always has one to spare.
+        for _ in 0..size.count.max(1) {
+            let sources = (0..count)
+                .filter(|&i| stacks[i].len() > 1)
+                .collect::<Vec<_>>();
+            let from = *rng.pick(&sources);
+            let to = (from + 1 + rng.below(count - 1)) % count;
+            let height = stacks[from].len();
+            let amount = 1 + rng.below(height - 1);
+            let moved = stacks[from].split_off(height - amount);
+            stacks[to].extend(moved);
+            data += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+        }
+        Ok(data)
+    }
+
+    fn part1(supplies: &Self::Input) -> crate::Result<String> {
+        rearrange(supplies, false)
+    }
+
+    fn part2(supplies: &Self::Input) -> crate::Result<String> {
+        rearrange(supplies, true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BoxesSlice;
+    use super::Command;
+    use super::Day05;
+    use crate::Error;
+    use crate::Solution;
+
+    #[test]
+    fn test_chars() {
+        let s = "[A] [B]     [D]    ";
+        let chars = BoxesSlice::new(s).collect::<Vec<char>>();
+        assert_eq!(chars, vec!['A', 'B', ' ', 'D', ' ']);
+    }
+
+    #[test]
+    fn test_command_from_str() {
+        let c = Command::parse("move 3 from 1 to 9", 9).unwrap();
+        assert_eq!((c.amount, c.from, c.to), (3, 0, 8));
+
+        let err = Command::parse("move 3 from 0 to 9", 9).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "column 13: expected a stack number from 1 to 9, found \"0\""
+        );
+        let err = Command::parse("move 3 from 1 to 9", 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "column 18: expected a stack number from 1 to 3, found \"9\""
+        );
+    }
+
+    #[test]
+    fn test_bad_moves() {
+        let drawing = "[A]        \n[B] [C] [D]\n 1   2   3 \n\n";
+        let supplies = Day05::parse(&format!("{}move 3 from 1 to 2\n", drawing)).unwrap();
+        assert_eq!(
+            Day05::part1(&supplies).unwrap_err().to_string(),
+            "cannot move 3 crates off stack 1, which holds 2"
+        );
+        let supplies = Day05::parse(&format!("{}move 2 from 1 to 2\n", drawing)).unwrap();
+        assert_eq!(
+            Day05::part2(&supplies).unwrap_err().to_string(),
+            "stack 1 ends up empty"
+        );
+        let err = Day05::parse(&format!("{}move 1 from 4 to 1\n", drawing))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 5: column 13: expected a stack number from 1 to 3, found \"4\" \
+             in \"move 1 from 4 to 1\""
+        );
+        let err = Day05::parse("[A] [B]\n 1 \n\nmove 1 from 1 to 1\n")
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "line 1: crate past stack 1 in \"[A] [B]\"");
+    }
+
+    #[test]
+    fn test_validate() {
+        let data = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n";
+        assert!(Day05::validate(data).is_empty());
+        // Columns shifted by one, a crate over an empty slot, a fourth stack
+        // and a move to it.
+        let data =
+            "     [D]    \n[N] [C]     [Q]\n[Z]     [P]\n 1   2   3 \n\nmove 1 from 2 to 4\n";
+        let errors = Day05::validate(data)
+            .iter()
+            .map(Error::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "line 1: column 5: expected a crate like \"[A]\" or spaces, \
+                 found \" [D\" in \"     [D]    \"",
+                "line 1: column 8: expected a space, found \"]\" in \"     [D]    \"",
+                "line 2: column 5: expected spaces over an empty slot, \
+                 found \"[C]\" in \"[N] [C]     [Q]\"",
+                "line 2: column 13: expected no crate past stack 3, \
+                 found \"[Q]\" in \"[N] [C]     [Q]\"",
+                "line 6: column 18: expected a stack number from 1 to 3, \
+                 found \"4\" in \"move 1 from 2 to 4\"",
+            ]
+        );
+        // Moves that take too many crates, or leave a stack empty, only show
+        // up once the moves are replayed.
+        let drawing = "[A]        \n[B] [C] [D]\n 1   2   3 \n\n";
+        let errors = Day05::validate(&format!(
+            "{}move 1 from 2 to 1\nmove 2 from 2 to 3\n",
+            drawing
+        ));
+        assert_eq!(
+            errors.iter().map(Error::to_string).collect::<Vec<_>>(),
+            vec![
+                "line 6: cannot move 2 crates off stack 2, which holds 0 \
+                  in \"move 2 from 2 to 3\""
+            ]
+        );
+        let errors = Day05::validate(&format!("{}move 1 from 2 to 1\n", drawing));
+        assert_eq!(
+            errors.iter().map(Error::to_string).collect::<Vec<_>>(),
+            vec!["stack 2 ends up empty"]
+        );
+    }
+}
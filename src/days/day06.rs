@@ -0,0 +1,184 @@
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::solution::StreamedAnswers;
+use crate::stream::Reader;
+use crate::validate;
+use crate::Error;
+use crate::Part;
+use crate::Solution;
+
+/// Looks for the end of the first run of `window_size` distinct bytes, fed
+/// one byte at a time and keeping only the last position of each byte value.
+struct MarkerSearch {
+    window_size: usize,
+    last_seen: [usize; 256],
+    run_start: usize,
+    position: usize,
+    found: Option<usize>,
+}
+
+impl MarkerSearch {
+    fn new(window_size: usize) -> Self {
+        MarkerSearch {
+            window_size,
+            last_seen: [0; 256],
+            run_start: 1,
+            position: 0,
+            found: None,
+        }
+    }
+
+    fn push(&mut self, b: u8) {
+        if self.found.is_some() {
+            return;
+        }
+        self.position += 1;
+        self.run_start = self.run_start.max(self.last_seen[b as usize] + 1);
+        self.last_seen[b as usize] = self.position;
+        if self.position + 1 - self.run_start == self.window_size {
+            self.found = Some(self.position);
+        }
+    }
+}
+
+/// Finds the end of the first run of `window_size` distinct bytes, reading
+/// `bytes` once.
+fn get_first_offset(bytes: impl Iterator<Item = u8>, window_size: usize) -> usize {
+    let mut search = MarkerSearch::new(window_size);
+    for b in bytes {
+        search.push(b);
+        if let Some(position) = search.found {
+            return position;
+        }
+    }
+    0
+}
+
+fn window_size(part: Part) -> usize {
+    match part {
+        Part::One => 4,
+        Part::Two => 14,
+    }
+}
+
+/// Looks for the markers of all `parts` at once, stopping at the end of
+/// the datastream's line or as soon as all are found.
+fn get_streamed_offsets(reader: &mut Reader, parts: &[Part]) -> crate::Result<Vec<String>> {
+    let mut searches = parts
+        .iter()
+        .map(|&part| MarkerSearch::new(window_size(part)))
+        .collect::<Vec<_>>();
+    for b in reader.bytes() {
+        let b = b?;
+        if b == b'\n' || b == b'\r' || searches.iter().all(|s| s.found.is_some()) {
+            break;
+        }
+        for search in &mut searches {
+            search.push(b);
+        }
+    }
+    Ok(searches
+        .iter()
+        .map(|s| s.found.unwrap_or(0).to_string())
+        .collect())
+}
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    /// The datastream is the first line; `validate` reports any others.
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        Ok(data.lines().next().unwrap_or("").to_string())
+    }
+
+    /// A single line of lowercase letters.
+    fn validate(data: &str) -> Vec<Error> {
+        let mut errors = validate::check_lines(1, data.lines(), |line| {
+            validate::chars(line, "a lowercase letter", |c| c.is_ascii_lowercase())
+        });
+        let count = data.trim_end().lines().count();
+        if count > 1 {
+            let message = format!("expected a single line, found {}", count);
+            errors.push(Error::structure(message));
+        }
+        errors
+    }
+
+    /// A datastream of `count` characters drawn from the first `extent`
+    /// letters, followed by a message marker so that both parts find one.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let alphabet = LETTERS.chars().collect::<Vec<_>>();
+        let prefix = &alphabet[..size.extent.clamp(1, alphabet.len())];
+        let mut data = (0..size.count)
+            .map(|_| *rng.pick(prefix))
+            .collect::<String>();
+        let mut marker = alphabet;
+        rng.shuffle(&mut marker);
+        data.extend(&marker[..14]);
+        data.push('\n');
+        Ok(data)
+    }
+
+    /// Scans the datastream as it is read, without keeping it.
+    fn solve_streamed(reader: &mut Reader, parts: &[Part]) -> StreamedAnswers {
+        Some(get_streamed_offsets(reader, parts))
+    }
+
+    fn part1(s: &Self::Input) -> crate::Result<usize> {
+        Ok(get_first_offset(s.bytes(), window_size(Part::One)))
+    }
+
+    fn part2(s: &Self::Input) -> crate::Result<usize> {
+        Ok(get_first_offset(s.bytes(), window_size(Part::Two)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::get_first_offset;
+    use super::get_streamed_offsets;
+    use super::Day06;
+    use crate::stream::Reader;
+    use crate::Part;
+    use crate::Solution;
+
+    #[test]
+    fn test_get_first_offset() {
+        let s = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(get_first_offset(s.bytes(), 4), 7);
+        assert_eq!(get_first_offset(s.bytes(), 14), 19);
+        assert_eq!(get_first_offset("aaaa".bytes(), 4), 0);
+    }
+
+    #[test]
+    fn test_get_streamed_offsets() {
+        let data = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n";
+        let mut reader = Reader::new(Box::new(data.as_bytes()), "input.txt");
+        assert_eq!(
+            get_streamed_offsets(&mut reader, &Part::ALL).unwrap(),
+            vec!["7", "19"]
+        );
+        let mut reader = Reader::new(Box::new("abcd\nefgh\n".as_bytes()), "input.txt");
+        assert_eq!(
+            get_streamed_offsets(&mut reader, &[Part::Two]).unwrap(),
+            vec!["0"]
+        );
+    }
+
+    #[test]
+    fn test_whole_and_streamed_agree() {
+        let data = "abc\nabcd\n";
+        let input = Day06::parse(data).unwrap();
+        let whole = [Day06::part1(&input).unwrap(), Day06::part2(&input).unwrap()];
+        let mut reader = Reader::new(Box::new(data.as_bytes()), "input.txt");
+        let streamed = get_streamed_offsets(&mut reader, &Part::ALL).unwrap();
+        assert_eq!(whole.map(|a| a.to_string()).to_vec(), streamed);
+        assert_eq!(streamed, vec!["0", "0"]);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::template::Template;
+use crate::validate;
+use crate::Error;
+use crate::Solution;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Debug)]
+struct File {
+    size: u32,
+}
+
+#[derive(Debug)]
+pub struct Dir {
+    nodes: HashMap<String, Node>,
+}
+
+#[derive(Debug)]
+enum Line {
+    Cd(String),
+    Ls,
+    Dir(String),
+    File(String, u32),
+}
+
+#[derive(Debug)]
+enum Node {
+    Dir(Dir),
+    File(File),
+}
+
+impl Node {
+    fn get_dir(&mut self) -> Option<&mut Dir> {
+        match self {
+            Self::Dir(dir) => Some(dir),
+            Self::File(_) => None,
+        }
+    }
+}
+
+impl Dir {
+    fn new() -> Self {
+        Dir {
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn make_directory(&mut self, name: String) {
+        self.nodes.insert(name, Node::Dir(Dir::new()));
+    }
+
+    fn make_file(&mut self, name: String, size: u32) {
+        self.nodes.insert(name, Node::File(File { size }));
+    }
+
+    fn get_directory<'a>(&'a mut self, path: &[&str]) -> crate::Result<&'a mut Dir> {
+        let mut cur = self;
+        for name in path {
+            cur = cur
+                .nodes
+                .entry(String::from(*name))
+                .or_insert_with(|| {
+                    Node::Dir(Dir {
+                        nodes: HashMap::new(),
+                    })
+                })
+                .get_dir()
+                .ok_or_else(|| Error::structure(format!("{} is a file, not a directory", name)))?;
+        }
+        Ok(cur)
+    }
+
+    fn size(&self) -> u32 {
+        self.nodes
+            .values()
+            .map(|node| match node {
+                Node::Dir(dir) => dir.size(),
+                Node::File(file) => file.size,
+            })
+            .sum()
+    }
+
+    fn filter_map_into<T, F>(&self, acc: &mut Vec<T>, f: &F)
+    where
+        F: Fn(&Dir) -> Option<T>,
+    {
+        if let Some(result) = f(self) {
+            acc.push(result);
+        }
+        for node in self.nodes.values() {
+            if let Node::Dir(dir) = node {
+                dir.filter_map_into(acc, f);
+            }
+        }
+    }
+}
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = if s == "$ ls" {
+            Line::Ls
+        } else if s.starts_with("$ ") {
+            let (name,) = Template::new("$ cd {}").parse(s)?;
+            Line::Cd(name)
+        } else if s.starts_with("dir ") {
+            let (name,) = Template::new("dir {}").parse(s)?;
+            Line::Dir(name)
+        } else {
+            let (size, name) = Template::new("{} {}").parse(s)?;
+            Line::File(name, size)
+        };
+        Ok(line)
+    }
+}
+
+fn make_file_tree(lines: &[Line]) -> crate::Result<Dir> {
+    let mut root = Dir {
+        nodes: HashMap::new(),
+    };
+    let mut path: Vec<&str> = Vec::new();
+    for line in lines {
+        match line {
+            Line::Ls => (),
+            Line::Cd(dir_name) => match dir_name.as_str() {
+                "/" => path.clear(),
+                ".." => {
+                    path.pop();
+                }
+                dir_name => {
+                    path.push(dir_name);
+                }
+            },
+            Line::Dir(name) => {
+                let dir = root.get_directory(&path)?;
+                dir.make_directory(name.clone());
+            }
+            Line::File(name, size) => {
+                let dir = root.get_directory(&path)?;
+                dir.make_file(name.clone(), *size);
+            }
+        }
+    }
+
+    Ok(root)
+}
+
+/// Writes the commands and output of listing `dir` and then each of its
+/// subdirectories depth first, ending back in `dir`.
+fn write_transcript(dir: &Dir, out: &mut String) {
+    let mut names = dir.nodes.keys().collect::<Vec<_>>();
+    names.sort();
+    out.push_str("$ ls\n");
+    for name in &names {
+        match &dir.nodes[*name] {
+            Node::Dir(_) => *out += &format!("dir {}\n", name),
+            Node::File(file) => *out += &format!("{} {}\n", file.size, name),
+        }
+    }
+    for name in names {
+        if let Node::Dir(subdir) = &dir.nodes[name] {
+            *out += &format!("$ cd {}\n", name);
+            write_transcript(subdir, out);
+            out.push_str("$ cd ..\n");
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Dir;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        let lines = crate::parse_lines::<Line>(data)?;
+        make_file_tree(&lines)
+    }
+
+    fn validate(data: &str) -> Vec<Error> {
+        validate::check_lines(1, data.lines(), validate::parses::<Line>)
+    }
+
+    /// A file system of `count` files and directories, nested at most
+    /// `extent` deep, using between 40000001 and 70000000 so that part two
+    /// has something to delete.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+        const EXTENSIONS: [&str; 4] = ["txt", "dat", "log", "bin"];
+        let max_file_size = (60000000 / size.count.max(1) as i64).clamp(1, 300000);
+        let mut root = Dir::new();
+        let mut dirs = vec![Vec::new()];
+        for _ in 0..size.count {
+            let path = rng.pick(&dirs).clone();
+            let path_refs = path.iter().map(String::as_str).collect::<Vec<_>>();
+            let dir = root.get_directory(&path_refs)?;
+            // Only file names have a dot, so the two never clash.
+            if path.len() < size.extent && rng.chance(0.3) {
+                let name = rng.word(LETTERS, 4);
+                if !dir.nodes.contains_key(&name) {
+                    dir.make_directory(name.clone());
+                    dirs.push([path, vec![name]].concat());
+                }
+            } else {
+                let name = format!("{}.{}", rng.word(LETTERS, 4), rng.pick(&EXTENSIONS));
+                dir.make_file(name, rng.range(1..=max_file_size) as u32);
+            }
+        }
+        let used = root.size() as i64;
+        let target = rng.range(40000001..=70000000);
+        if used < target {
+            root.make_file("core.1".to_string(), (target - used) as u32);
+        }
+        let mut data = "$ cd /\n".to_string();
+        write_transcript(&root, &mut data);
+        Ok(data)
+    }
+
+    fn part1(root: &Self::Input) -> crate::Result<u32> {
+        let mut mapped: Vec<u32> = Vec::new();
+        root.filter_map_into(&mut mapped, &|d| {
+            let size = d.size();
+            if size <= 100000 {
+                Some(size)
+            } else {
+                None
+            }
+        });
+        Ok(mapped.iter().sum::<u32>())
+    }
+
+    fn part2(root: &Self::Input) -> crate::Result<u32> {
+        const TOTAL_SPACE: i32 = 70000000;
+        const NEEDED_SPACE: i32 = 30000000;
+        let total_size = root.size() as i32;
+        let free_space = TOTAL_SPACE - total_size;
+        let min_dir_size = NEEDED_SPACE - free_space;
+        let mut candidates_for_deletion = Vec::new();
+        root.filter_map_into(&mut candidates_for_deletion, &|d| {
+            let size = d.size();
+            if size as i32 >= min_dir_size {
+                Some(size)
+            } else {
+                None
+            }
+        });
+        candidates_for_deletion
+            .into_iter()
+            .min()
+            .ok_or_else(|| Error::structure("no directory is large enough to free the space"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day07;
+    use crate::Solution;
+
+    #[test]
+    fn test_cd_into_file() {
+        let err = Day07::parse("$ cd /\n$ ls\n10 a\n$ cd a\n$ ls\n5 b\n")
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "a is a file, not a directory");
+    }
+}
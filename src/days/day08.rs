@@ -0,0 +1,172 @@
+use crate::frames::Discard;
+use crate::frames::Frames;
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::geom::Direction4;
+use crate::grid::Grid;
+use crate::grid::Pos;
+use crate::grid::Ray;
+use crate::solution::no_visualisation;
+use crate::validate;
+use crate::Error;
+use crate::Part;
+use crate::Solution;
+use bitflags::bitflags;
+
+bitflags! {
+    struct VisibilityFlags: u8 {
+        const L = 0b0001;
+        const R = 0b0010;
+        const T = 0b0100;
+        const B = 0b1000;
+    }
+}
+
+fn fill_visibility(
+    heights: &Grid<i32>,
+    visibility: &mut Grid<VisibilityFlags>,
+    line: Ray,
+    flag: VisibilityFlags,
+) {
+    let mut max = -1;
+    for pos in line {
+        let value = heights[pos];
+        if value > max {
+            max = value;
+            visibility[pos] |= flag;
+        }
+    }
+}
+
+/// Trees seen from outside so far as `#`, the others as `.`.
+fn draw(visibility: &Grid<VisibilityFlags>) -> Grid<char> {
+    visibility.map(|v| if v.is_empty() { '.' } else { '#' })
+}
+
+fn get_visibilities(
+    heights: &Grid<i32>,
+    frames: &mut dyn Frames,
+) -> crate::Result<Grid<VisibilityFlags>> {
+    let (w, h) = (heights.width(), heights.height());
+    let mut visibility = Grid::new(w, h, VisibilityFlags::empty());
+    for row in 0..h {
+        fill_visibility(
+            heights,
+            &mut visibility,
+            heights.line((0, row), Direction4::Right.step()),
+            VisibilityFlags::L,
+        );
+        fill_visibility(
+            heights,
+            &mut visibility,
+            heights.line((w - 1, row), Direction4::Left.step()),
+            VisibilityFlags::R,
+        );
+        frames.step(&|| draw(&visibility).into())?;
+    }
+    for col in 0..w {
+        fill_visibility(
+            heights,
+            &mut visibility,
+            heights.line((col, 0), Direction4::Down.step()),
+            VisibilityFlags::B,
+        );
+        fill_visibility(
+            heights,
+            &mut visibility,
+            heights.line((col, h - 1), Direction4::Up.step()),
+            VisibilityFlags::T,
+        );
+        frames.step(&|| draw(&visibility).into())?;
+    }
+    frames.end(&|| draw(&visibility).into())?;
+    Ok(visibility)
+}
+
+fn count_visible_trees(heights: &Grid<i32>, reference_height: i32, ray: Ray) -> i32 {
+    let mut count = 0;
+    for pos in ray {
+        count += 1;
+        if heights[pos] >= reference_height {
+            break;
+        }
+    }
+    count
+}
+
+fn get_scenic_score(heights: &Grid<i32>, pos: Pos) -> i32 {
+    Direction4::ALL
+        .iter()
+        .map(|d| count_visible_trees(heights, heights[pos], heights.ray(pos, d.step())))
+        .product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<i32>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        Grid::parse(data, "a digit", |c| c.to_digit(10).map(|d| d as i32))
+    }
+
+    /// A rectangular grid of digits.
+    fn validate(data: &str) -> Vec<Error> {
+        validate::grid(data, "a digit", |c| c.is_ascii_digit())
+    }
+
+    /// A square grid of trees `extent` on a side.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let side = size.extent.max(1);
+        let mut data = String::new();
+        for _ in 0..side {
+            data.extend((0..side).map(|_| char::from(b'0' + rng.below(10) as u8)));
+            data.push('\n');
+        }
+        Ok(data)
+    }
+
+    fn part1(heights: &Self::Input) -> crate::Result<usize> {
+        let visibility = get_visibilities(heights, &mut Discard)?;
+        Ok(visibility.cells().filter(|v| !v.is_empty()).count())
+    }
+
+    fn part2(heights: &Self::Input) -> crate::Result<i32> {
+        let max_score = heights
+            .positions()
+            .map(|pos| get_scenic_score(heights, pos))
+            .max();
+        Ok(max_score.unwrap_or(0))
+    }
+
+    fn visualise(heights: &Self::Input, part: Part, frames: &mut dyn Frames) -> crate::Result<()> {
+        match part {
+            Part::One => get_visibilities(heights, frames).map(|_| ()),
+            Part::Two => Err(no_visualisation(part)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::get_scenic_score;
+    use super::Day08;
+    use crate::Solution;
+
+    const EXAMPLE: &str = "30373\n25512\n65332\n33549\n35390\n";
+
+    #[test]
+    fn test_visible_trees() {
+        let heights = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part1(&heights).unwrap(), 21);
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let heights = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(get_scenic_score(&heights, (2, 1)), 4);
+        assert_eq!(get_scenic_score(&heights, (2, 3)), 8);
+    }
+}
@@ -0,0 +1,199 @@
+use crate::frames::Discard;
+use crate::frames::Frame;
+use crate::frames::Frames;
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::geom::Direction4;
+use crate::geom::Point2;
+use crate::sparse_grid::SparseGrid;
+use crate::template::Template;
+use crate::validate;
+use crate::Error;
+use crate::Part;
+use crate::Solution;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct Move {
+    direction: Direction4,
+    amount: i32,
+}
+
+impl FromStr for Move {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, amount) = Template::new("{} {}").parse(s)?;
+        Ok(Move { direction, amount })
+    }
+}
+
+#[derive(Debug)]
+struct Rope {
+    knots: Vec<Point2>,
+}
+
+impl Rope {
+    fn new(count: usize) -> Self {
+        let knots = vec![Point2::ORIGIN; count];
+        Rope { knots }
+    }
+
+    #[cfg(test)]
+    fn head(&self) -> Point2 {
+        self.knots[0]
+    }
+
+    fn tail(&self) -> Point2 {
+        *self.knots.last().unwrap()
+    }
+
+    fn make_move(&mut self, direction: Direction4) {
+        self.knots[0] += direction.step();
+        for i in 0..self.knots.len() - 1 {
+            let head = self.knots[i];
+            let tail = self.knots[i + 1];
+            let distance = head.chebyshev(tail);
+            debug_assert!(distance <= 2);
+            if distance == 2 {
+                self.knots[i + 1] += (head - tail).signum();
+            }
+        }
+    }
+
+    /// The knots over the cells the tail has visited, `#`. The head is `H`
+    /// and the others are numbered, except for a lone tail, `T`.
+    fn draw(&self, visited: &HashSet<Point2>) -> Frame {
+        let mut map = SparseGrid::new();
+        for &p in visited {
+            map.insert(p, '#');
+        }
+        for (i, &knot) in self.knots.iter().enumerate().rev() {
+            let glyph = match i {
+                0 => 'H',
+                1 if self.knots.len() == 2 => 'T',
+                i => char::from_digit(i as u32, 10).unwrap_or('*'),
+            };
+            map.insert(knot, glyph);
+        }
+        let bounds = map.bounds().expect("the rope has knots");
+        let head = self.knots[0] - bounds.min;
+        Frame::with_focus(
+            map.to_grid(bounds, |c| c.copied().unwrap_or('.')),
+            (head.x as usize, head.y as usize),
+        )
+    }
+}
+
+fn get_tail_positions_count(
+    knots_count: usize,
+    moves: &[Move],
+    frames: &mut dyn Frames,
+) -> crate::Result<usize> {
+    let mut rope = Rope::new(knots_count);
+    let mut tail_positions = HashSet::new();
+    tail_positions.insert(rope.tail());
+    for m in moves {
+        for _ in 0..m.amount {
+            rope.make_move(m.direction);
+            tail_positions.insert(rope.tail());
+            frames.step(&|| rope.draw(&tail_positions))?;
+        }
+    }
+    frames.end(&|| rope.draw(&tail_positions))?;
+    Ok(tail_positions.len())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn validate(data: &str) -> Vec<Error> {
+        validate::check_lines(1, data.lines(), validate::parses::<Move>)
+    }
+
+    /// `count` moves of up to `extent` steps.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let mut data = String::new();
+        for _ in 0..size.count.max(1) {
+            let direction = rng.pick(&['U', 'D', 'L', 'R']);
+            data += &format!("{} {}\n", direction, 1 + rng.below(size.extent.max(1)));
+        }
+        Ok(data)
+    }
+
+    fn part1(moves: &Self::Input) -> crate::Result<usize> {
+        get_tail_positions_count(2, moves, &mut Discard)
+    }
+
+    fn part2(moves: &Self::Input) -> crate::Result<usize> {
+        get_tail_positions_count(10, moves, &mut Discard)
+    }
+
+    fn visualise(moves: &Self::Input, part: Part, frames: &mut dyn Frames) -> crate::Result<()> {
+        let knots_count = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        get_tail_positions_count(knots_count, moves, frames)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Move;
+    use super::Rope;
+    use crate::geom::point2;
+    use crate::geom::Direction4;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_move_from_str() {
+        assert_eq!(
+            Move::from_str("U 4").unwrap(),
+            Move {
+                direction: Direction4::Up,
+                amount: 4
+            }
+        );
+        assert_eq!(
+            Move::from_str("D 3").unwrap(),
+            Move {
+                direction: Direction4::Down,
+                amount: 3
+            }
+        );
+        assert_eq!(
+            Move::from_str("L 2").unwrap(),
+            Move {
+                direction: Direction4::Left,
+                amount: 2
+            }
+        );
+        assert_eq!(
+            Move::from_str("R 1").unwrap(),
+            Move {
+                direction: Direction4::Right,
+                amount: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_rope() {
+        let mut r = Rope::new(2);
+        r.knots[0] = point2(1, -1);
+        r.make_move(Direction4::Up);
+        assert_eq!(r.head(), point2(1, -2));
+        assert_eq!(r.tail(), point2(1, -1));
+    }
+}
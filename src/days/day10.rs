@@ -0,0 +1,206 @@
+use crate::frames::Discard;
+use crate::frames::Frame;
+use crate::frames::Frames;
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::grid::Grid;
+use crate::ocr;
+use crate::template::Template;
+use crate::validate;
+use crate::Error;
+use crate::Part;
+use crate::Solution;
+
+#[derive(Debug, PartialEq)]
+pub enum Op {
+    Noop,
+    Addx(i32),
+}
+
+impl std::str::FromStr for Op {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "noop" {
+            return Ok(Op::Noop);
+        }
+        let (v,) = Template::new("addx {}").parse(s)?;
+        Ok(Op::Addx(v))
+    }
+}
+
+struct Cycles<'a> {
+    ops: std::slice::Iter<'a, Op>,
+    reg: i32,
+    remaining_cycles: i32,
+    op_value: i32,
+}
+
+impl<'a> Cycles<'a> {
+    fn new(ops: &'a [Op]) -> Self {
+        Cycles {
+            ops: ops.iter(),
+            reg: 1,
+            remaining_cycles: 0,
+            op_value: 0,
+        }
+    }
+}
+
+impl Iterator for Cycles<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_cycles == 0 {
+            let op = self.ops.next();
+            match op {
+                Some(o) => {
+                    self.reg += self.op_value;
+                    match o {
+                        Op::Noop => {
+                            self.op_value = 0;
+                            self.remaining_cycles = 0;
+                        }
+                        Op::Addx(v) => {
+                            self.op_value = *v;
+                            self.remaining_cycles = 1;
+                        }
+                    }
+                    Some(self.reg)
+                }
+                None => None,
+            }
+        } else {
+            self.remaining_cycles -= 1;
+            Some(self.reg)
+        }
+    }
+}
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+fn is_lit(x: usize, reg: i32) -> bool {
+    (x as i32 - reg).abs() < 2
+}
+
+/// The CRT image drawn while running `ops`, `true` for lit pixels. Each
+/// cycle is a step of `frames`, following the beam.
+fn draw(ops: &[Op], frames: &mut dyn Frames) -> crate::Result<Grid<bool>> {
+    let mut screen = Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, false);
+    let picture = |screen: &Grid<bool>| screen.map(|&lit| if lit { '#' } else { '.' });
+    for (i, v) in Cycles::new(ops)
+        .enumerate()
+        .take(SCREEN_WIDTH * SCREEN_HEIGHT)
+    {
+        let pos = (i % SCREEN_WIDTH, i / SCREEN_WIDTH);
+        screen[pos] = is_lit(pos.0, v);
+        frames.step(&|| Frame::with_focus(picture(&screen), pos))?;
+    }
+    frames.end(&|| picture(&screen).into())?;
+    Ok(screen)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Op>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn validate(data: &str) -> Vec<Error> {
+        validate::check_lines(1, data.lines(), validate::parses::<Op>)
+    }
+
+    /// A program drawing eight random letters; the screen fixes its size,
+    /// so `size` is not used.
+    fn generate(rng: &mut Rng, _size: Size) -> crate::Result<String> {
+        let letters = ocr::letters().collect::<Vec<_>>();
+        // The sprite sits at 1 until the first instruction is done, which
+        // lights the first two pixels: only some letters can come first.
+        let screen = loop {
+            let text = (0..8).map(|_| *rng.pick(&letters)).collect::<String>();
+            let screen = ocr::render(&text)?;
+            if screen[(0, 0)] && screen[(1, 0)] {
+                break screen;
+            }
+        };
+        // Each instruction takes two cycles, during which the sprite must
+        // light exactly the lit pixels of a pair of columns. There is a
+        // position that does so for every pair.
+        let pixels = screen.cells().copied().collect::<Vec<_>>();
+        let mut data = String::new();
+        let mut reg = 1;
+        for (i, pair) in pixels.chunks(2).enumerate().skip(1) {
+            let x = (2 * i % SCREEN_WIDTH) as i32;
+            let fits = |reg: i32| {
+                is_lit(x as usize, reg) == pair[0] && is_lit(x as usize + 1, reg) == pair[1]
+            };
+            let next = if fits(reg) && rng.chance(0.5) {
+                reg
+            } else {
+                let candidates = (-1..=SCREEN_WIDTH as i32)
+                    .filter(|&r| fits(r))
+                    .collect::<Vec<_>>();
+                *rng.pick(&candidates)
+            };
+            if next == reg {
+                data += "noop\nnoop\n";
+            } else {
+                data += &format!("addx {}\n", next - reg);
+            }
+            reg = next;
+        }
+        data += "noop\nnoop\n";
+        Ok(data)
+    }
+
+    fn part1(ops: &Self::Input) -> crate::Result<i32> {
+        let cycles = Cycles::new(ops);
+        let sum = cycles
+            .enumerate()
+            .take(220)
+            .skip(19)
+            .step_by(40)
+            .map(|(i, v)| (i + 1) as i32 * v)
+            .sum::<i32>();
+        Ok(sum)
+    }
+
+    fn part2(ops: &Self::Input) -> crate::Result<String> {
+        ocr::recognise(&draw(ops, &mut Discard)?)
+    }
+
+    /// Both parts show the beam drawing the screen.
+    fn visualise(ops: &Self::Input, _part: Part, frames: &mut dyn Frames) -> crate::Result<()> {
+        draw(ops, frames).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cycles;
+    use super::Op;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_op_from_string() {
+        assert_eq!(Op::from_str("noop").unwrap(), Op::Noop);
+        assert_eq!(Op::from_str("addx 3").unwrap(), Op::Addx(3));
+    }
+
+    #[test]
+    fn test_cycles() {
+        let ops = [Op::Noop, Op::Addx(3), Op::Addx(1), Op::Noop];
+        // The register starts at 1; these are the sums added to it so far.
+        let mut cycles = Cycles::new(&ops).map(|reg| reg - 1);
+        assert_eq!(cycles.next(), Some(0));
+        assert_eq!(cycles.next(), Some(0));
+        assert_eq!(cycles.next(), Some(0));
+        assert_eq!(cycles.next(), Some(3));
+    }
+}
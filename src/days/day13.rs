@@ -0,0 +1,278 @@
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::validate;
+use crate::Error;
+use crate::Solution;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[derive(Debug, Eq, Clone)]
+enum Value {
+    Int(i32),
+    List(Vec<Value>),
+}
+
+#[derive(Debug, Eq, Clone)]
+pub struct Packet {
+    content: Vec<Value>,
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    cursor: usize,
+}
+
+impl FromStr for Packet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut stack = Vec::new();
+        let mut curr = Vec::<Value>::new();
+        let parser = Parser::new(s);
+        for tok in parser.skip(1) {
+            match tok {
+                "[" => {
+                    stack.push(curr);
+                    curr = Vec::new();
+                }
+                "]" => {
+                    if let Some(mut v) = stack.pop() {
+                        v.push(Value::List(curr));
+                        curr = v;
+                    }
+                }
+                "," => (),
+                v => curr.push(Value::Int(v.parse::<i32>().map_err(|_| {
+                    Error::unexpected_in(s, v, "'[', ']', ',' or a number")
+                })?)),
+            }
+        }
+        Ok(Packet { content: curr })
+    }
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Self {
+        Self { s, cursor: 0 }
+    }
+
+    fn next_token(&mut self) -> Option<&'a str> {
+        let bytes = self.s.as_bytes();
+        let mut cursor = self.cursor;
+        while cursor < bytes.len() && bytes[cursor] == b' ' {
+            cursor += 1;
+        }
+
+        if cursor == self.s.len() {
+            return None;
+        }
+
+        let mut end = cursor + 1;
+        let b = bytes[cursor];
+        if b.is_ascii_digit() {
+            while end < bytes.len() {
+                match bytes[end] {
+                    b'0'..=b'9' => end += 1,
+                    _ => break,
+                }
+            }
+        }
+        self.cursor = end;
+        Some(&self.s[cursor..end])
+    }
+}
+
+impl<'a> Iterator for Parser<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token()
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        compare_lists(&self.content, &other.content) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_lists(&self.content, &other.content)
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        compare_values(self, other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_values(self, other)
+    }
+}
+
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Int(ia), Value::Int(ib)) => ia.cmp(ib),
+        (Value::Int(ia), Value::List(lb)) => compare_lists(&[Value::Int(*ia)], lb),
+        (Value::List(la), Value::Int(ib)) => compare_lists(la, &[Value::Int(*ib)]),
+        (Value::List(la), Value::List(lb)) => compare_lists(la, lb),
+    }
+}
+
+fn compare_lists(a: &[Value], b: &[Value]) -> Ordering {
+    let mut index = 0;
+    loop {
+        if index == a.len() && index == b.len() {
+            return Ordering::Equal;
+        }
+        if index == a.len() {
+            return Ordering::Less;
+        }
+        if index == b.len() {
+            return Ordering::Greater;
+        }
+        let ordering = compare_values(&a[index], &b[index]);
+        if ordering == Ordering::Equal {
+            index += 1;
+        } else {
+            return ordering;
+        }
+    }
+}
+
+/// A list of up to `max_len` values: numbers, or lists while `depth`
+/// allows.
+fn generate_list(rng: &mut Rng, depth: usize, max_len: usize) -> String {
+    let len = rng.below(max_len + 1);
+    let values = (0..len)
+        .map(|_| {
+            if depth > 0 && rng.chance(0.3) {
+                generate_list(rng, depth - 1, max_len)
+            } else {
+                rng.below(11).to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", values.join(","))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Packet>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_non_empty_lines(data)
+    }
+
+    /// Pairs of packets separated by blank lines.
+    fn validate(data: &str) -> Vec<Error> {
+        let mut errors = Vec::new();
+        for group in crate::groups(data) {
+            errors.extend(validate::check_lines(
+                group.first_line,
+                group.lines(),
+                validate::parses::<Packet>,
+            ));
+            let count = group.lines().count();
+            if count != 2 {
+                let message = format!("expected a pair of packets, found {}", count);
+                let first = group.lines().next().unwrap_or("");
+                errors.push(Error::structure(message).at_line(group.first_line, first));
+            }
+        }
+        errors
+    }
+
+    /// `count` pairs of packets whose lists hold up to `extent` values,
+    /// nested at most three deep. The packets of a pair are never equal,
+    /// nor equal to a divider packet, so every ordering is decided.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let dividers = [Packet::from_str("[[2]]")?, Packet::from_str("[[6]]")?];
+        let max_len = size.extent.max(1);
+        let mut pairs = Vec::new();
+        while pairs.len() < size.count.max(1) {
+            let (left, right) = (
+                generate_list(rng, 3, max_len),
+                generate_list(rng, 3, max_len),
+            );
+            let packets = [Packet::from_str(&left)?, Packet::from_str(&right)?];
+            if packets[0] != packets[1] && !packets.iter().any(|p| dividers.contains(p)) {
+                pairs.push(format!("{}\n{}\n", left, right));
+            }
+        }
+        Ok(pairs.join("\n"))
+    }
+
+    fn part1(packets: &Self::Input) -> crate::Result<usize> {
+        let sum = packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(i, _)| i + 1)
+            .sum::<usize>();
+        Ok(sum)
+    }
+
+    fn part2(packets: &Self::Input) -> crate::Result<usize> {
+        let mut packets = packets.clone();
+        let p2 = Packet::from_str("[[2]]")?;
+        let p6 = Packet::from_str("[[6]]")?;
+        packets.push(p2.clone());
+        packets.push(p6.clone());
+        packets.sort();
+        let filtered = packets
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| **p == p2 || **p == p6)
+            .map(|(i, _)| i + 1)
+            .collect::<Vec<_>>();
+        Ok(filtered[0] * filtered[1])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::compare_lists;
+    use super::Packet;
+    use super::Parser;
+    use std::str::FromStr;
+
+    #[test]
+    fn test() {
+        let parser = Parser::new("[1, [2, [3, 4]], 5]");
+        let tokens = parser.collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            &["[", "1", ",", "[", "2", ",", "[", "3", ",", "4", "]", "]", ",", "5", "]"]
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let a = Packet::from_str("[1,[2,[3,[4,[5,6,7]]]],8,9]").unwrap();
+        let b = Packet::from_str("[1,[2,[3,[4,[5,6,0]]]],8,9]").unwrap();
+        assert_eq!(
+            compare_lists(&a.content, &b.content),
+            std::cmp::Ordering::Greater
+        );
+    }
+}
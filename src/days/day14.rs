@@ -0,0 +1,285 @@
+use crate::frames::Discard;
+use crate::frames::Frame;
+use crate::frames::Frames;
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::geom::point2;
+use crate::geom::Point2;
+use crate::sparse_grid::Bounds;
+use crate::sparse_grid::SparseGrid;
+use crate::template::Template;
+use crate::validate;
+use crate::Error;
+use crate::Part;
+use crate::Solution;
+use std::str::FromStr;
+
+/// Where the sand pours in.
+const SOURCE: Point2 = point2(500, 0);
+
+struct Cave {
+    map: SparseGrid<Cell>,
+    implicit_bottom_y: Option<i64>,
+}
+
+#[derive(Debug)]
+pub struct Path(Vec<Point2>);
+
+enum Cell {
+    Rock,
+    Sand,
+}
+
+impl FromStr for Path {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let points = s
+            .split("->")
+            .map(|p| {
+                let p = p.trim();
+                let offset = crate::slice_offset(s, p);
+                let (x, y) = Template::new("{},{}")
+                    .parse(p)
+                    .map_err(|e| e.offset(offset))?;
+                Ok(point2(x, y))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        if let Some(w) = points
+            .windows(2)
+            .find(|w| (w[0].x == w[1].x) == (w[0].y == w[1].y))
+        {
+            return Err(Error::structure(format!(
+                "segment {} -> {} is not horizontal or vertical",
+                w[0], w[1]
+            )));
+        }
+        Ok(Path(points))
+    }
+}
+
+impl Cave {
+    fn new(paths: &[Path]) -> Self {
+        let mut map = SparseGrid::new();
+        for p in paths {
+            p.fill(&mut map);
+        }
+        Cave {
+            map,
+            implicit_bottom_y: None,
+        }
+    }
+
+    fn max_y(&self) -> i64 {
+        self.map.bounds().map_or(0, |b| b.max.y)
+    }
+
+    fn get_bottom(&self, p: Point2) -> Option<Point2> {
+        match self.map.first_in_column(p.x, p.y, true) {
+            Some((bottom, _)) => Some(bottom),
+            None => self.implicit_bottom_y.map(|y| point2(p.x, y)),
+        }
+    }
+
+    fn get_rest_position(&self, p: Point2) -> Option<Point2> {
+        let mut curr = p;
+        loop {
+            if let Some(bottom) = self.get_bottom(curr) {
+                if curr == bottom {
+                    return Some(curr);
+                }
+                curr = bottom - point2(0, 1);
+                if let Some(bottom_y) = self.implicit_bottom_y {
+                    if bottom_y == bottom.y {
+                        return Some(curr);
+                    }
+                }
+                let left = curr + point2(-1, 1);
+                if self.map.contains(left) {
+                    let right = curr + point2(1, 1);
+                    if self.map.contains(right) {
+                        return Some(curr);
+                    } else {
+                        curr = right;
+                    }
+                } else {
+                    curr = left;
+                }
+            } else {
+                return None;
+            }
+        }
+    }
+
+    fn drop_sand(&mut self, p: Point2) -> Option<Point2> {
+        let pos = self.get_rest_position(p)?;
+        self.map.insert(pos, Cell::Sand);
+        Some(pos)
+    }
+
+    /// The cave as `#` for rock and `o` for sand, down to the floor if
+    /// there is one, following the grain that came to rest last.
+    fn draw(&self, last: Option<Point2>) -> Frame {
+        let mut bounds = self.map.bounds().unwrap_or(Bounds {
+            min: SOURCE,
+            max: SOURCE,
+        });
+        if let Some(y) = self.implicit_bottom_y {
+            bounds.max.y = y;
+        }
+        let mut grid = self.map.to_grid(bounds, |cell| match cell {
+            Some(Cell::Rock) => '#',
+            Some(Cell::Sand) => 'o',
+            None => '.',
+        });
+        if self.implicit_bottom_y.is_some() {
+            let floor = grid.height() - 1;
+            for x in 0..grid.width() {
+                grid[(x, floor)] = '#';
+            }
+        }
+        match last {
+            Some(p) => Frame::with_focus(
+                grid,
+                ((p.x - bounds.min.x) as usize, (p.y - bounds.min.y) as usize),
+            ),
+            None => grid.into(),
+        }
+    }
+}
+
+impl Path {
+    fn fill(&self, map: &mut SparseGrid<Cell>) {
+        for line in self.0.windows(2) {
+            let (a, b) = (line[0], line[1]);
+            let step = (b - a).signum();
+            assert!(step.x == 0 || step.y == 0);
+            let mut p = a;
+            map.insert(p, Cell::Rock);
+            while p != b {
+                p += step;
+                map.insert(p, Cell::Rock);
+            }
+        }
+    }
+}
+
+fn count_sand_until_abyss(paths: &[Path], frames: &mut dyn Frames) -> crate::Result<usize> {
+    let mut cave = Cave::new(paths);
+    let drop_position = SOURCE;
+    let mut count = 0;
+    loop {
+        let rest_pos = cave.drop_sand(drop_position);
+        frames.step(&|| cave.draw(rest_pos))?;
+        if rest_pos.is_none() {
+            break;
+        } else {
+            count += 1;
+        }
+    }
+    frames.end(&|| cave.draw(None))?;
+    Ok(count)
+}
+
+fn count_sand_until_blocked(paths: &[Path], frames: &mut dyn Frames) -> crate::Result<usize> {
+    let mut cave = Cave::new(paths);
+    cave.implicit_bottom_y = Some(cave.max_y() + 2);
+    let drop_position = SOURCE;
+    let mut count = 0;
+    loop {
+        let rest_pos = cave.drop_sand(drop_position);
+        frames.step(&|| cave.draw(rest_pos))?;
+        if let Some(p) = rest_pos {
+            count += 1;
+            if p == drop_position {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+    frames.end(&|| cave.draw(None))?;
+    Ok(count)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Path>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn validate(data: &str) -> Vec<Error> {
+        validate::check_lines(1, data.lines(), validate::parses::<Path>)
+    }
+
+    /// `count` rock paths of up to four segments of up to `extent` steps,
+    /// below and around the sand source.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let extent = size.extent.max(1) as i64;
+        let spread = 5 * extent;
+        let mut data = String::new();
+        for _ in 0..size.count.max(1) {
+            let mut p = point2(500 + rng.range(-spread..=spread), rng.range(1..=spread));
+            let mut points = vec![format!("{},{}", p.x, p.y)];
+            let mut horizontal = rng.chance(0.5);
+            for _ in 0..1 + rng.below(4) {
+                let length = rng.range(1..=extent);
+                let length = if rng.chance(0.5) { length } else { -length };
+                if horizontal {
+                    p.x += length;
+                } else {
+                    // Keep the rock below the source.
+                    p.y = if p.y + length < 1 {
+                        p.y - length
+                    } else {
+                        p.y + length
+                    };
+                }
+                points.push(format!("{},{}", p.x, p.y));
+                horizontal = !horizontal;
+            }
+            data += &points.join(" -> ");
+            data.push('\n');
+        }
+        Ok(data)
+    }
+
+    fn part1(paths: &Self::Input) -> crate::Result<usize> {
+        count_sand_until_abyss(paths, &mut Discard)
+    }
+
+    fn part2(paths: &Self::Input) -> crate::Result<usize> {
+        count_sand_until_blocked(paths, &mut Discard)
+    }
+
+    fn visualise(paths: &Self::Input, part: Part, frames: &mut dyn Frames) -> crate::Result<()> {
+        // Without rock there is nothing to frame the drawing with.
+        if paths.is_empty() {
+            return Err(Error::structure("the cave has no rock"));
+        }
+        match part {
+            Part::One => count_sand_until_abyss(paths, frames)?,
+            Part::Two => count_sand_until_blocked(paths, frames)?,
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day14;
+    use crate::frames::Discard;
+    use crate::Part;
+    use crate::Solution;
+
+    #[test]
+    fn test_visualise_without_rock() {
+        let err = Day14::visualise(&Vec::new(), Part::One, &mut Discard).unwrap_err();
+        assert_eq!(err.to_string(), "the cave has no rock");
+    }
+}
@@ -0,0 +1,262 @@
+use crate::frames::Frames;
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::geom::point2;
+use crate::geom::Point2;
+use crate::grid::Grid;
+use crate::interval::Interval;
+use crate::interval::IntervalSet;
+use crate::params::Params;
+use crate::template::Template;
+use crate::validate;
+use crate::Error;
+use crate::Part;
+use crate::Solution;
+use std::str::FromStr;
+
+pub struct Sensors {
+    items: Vec<DataItem>,
+    line_index: i64,
+    max_index: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DataItem {
+    s: Point2,
+    b: Point2,
+}
+
+impl DataItem {
+    fn distance(&self) -> i64 {
+        self.s.manhattan(self.b)
+    }
+
+    /// The positions of the line that are closer to the sensor than its
+    /// beacon; empty if the line is out of reach.
+    fn get_coverage_interval(&self, line_index: i64) -> Interval {
+        let delta = self.distance() - (line_index - self.s.y).abs();
+        Interval::inclusive(self.s.x - delta, self.s.x + delta)
+    }
+}
+
+const REPORT: Template = Template::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+
+impl FromStr for DataItem {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (sx, sy, bx, by) = REPORT.parse(line)?;
+        Ok(Self {
+            s: point2(sx, sy),
+            b: point2(bx, by),
+        })
+    }
+}
+
+/// A sensor at `s` whose closest beacon is just nearer than `distress`,
+/// unless that would put the beacon on the sensor.
+fn generate_sensor(rng: &mut Rng, s: Point2, distress: Point2) -> Option<DataItem> {
+    let distance = s.manhattan(distress) - 1;
+    if distance < 1 {
+        return None;
+    }
+    let dx = rng.range(-distance..=distance);
+    let dy = distance - dx.abs();
+    let dy = if rng.chance(0.5) { dy } else { -dy };
+    Some(DataItem {
+        s,
+        b: point2(s.x + dx, s.y + dy),
+    })
+}
+
+fn get_line_coverage(data: &[DataItem], line_index: i64) -> IntervalSet {
+    data.iter()
+        .map(|item| item.get_coverage_interval(line_index))
+        .collect()
+}
+
+fn get_coverage_count(data: &[DataItem], line_index: i64) -> u64 {
+    let mut coverage = get_line_coverage(data, line_index);
+    for item in data {
+        if item.b.y == line_index {
+            coverage.remove(Interval::inclusive(item.b.x, item.b.x));
+        }
+    }
+    coverage.len()
+}
+
+fn find_not_covered(line_index: i64, max_x: i64, data: &[DataItem]) -> Option<i64> {
+    let coverage = get_line_coverage(data, line_index);
+    coverage
+        .gaps(Interval::inclusive(0, max_x))
+        .first()
+        .map(|gap| gap.start)
+}
+
+/// The largest search area that `draw` accepts, in cells per side.
+const MAX_DRAWN_SIZE: i64 = 1000;
+
+/// The search area with sensors `S`, beacons `B` and the positions covered
+/// by the first `count` sensors `#`.
+fn draw(sensors: &Sensors, count: usize) -> Grid<char> {
+    let size = sensors.max_index as usize + 1;
+    let mut grid = Grid::new(size, size, '.');
+    for pos in grid.positions().collect::<Vec<_>>() {
+        let p = point2(pos.0 as i64, pos.1 as i64);
+        if sensors.items[..count]
+            .iter()
+            .any(|item| item.s.manhattan(p) <= item.distance())
+        {
+            grid[pos] = '#';
+        }
+    }
+    for item in &sensors.items {
+        for (p, glyph) in [(item.s, 'S'), (item.b, 'B')] {
+            if let (Ok(x), Ok(y)) = (usize::try_from(p.x), usize::try_from(p.y)) {
+                if let Some(cell) = grid.get_mut((x, y)) {
+                    *cell = glyph;
+                }
+            }
+        }
+    }
+    grid
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Sensors;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    /// The puzzle asks about the line `row` and a search area from 0 to
+    /// `max` on both axes, 2000000 and 4000000 unless given.
+    fn parse_with(data: &str, params: &Params) -> crate::Result<Self::Input> {
+        Ok(Sensors {
+            items: crate::parse_lines::<DataItem>(data)?,
+            line_index: params.get("row").unwrap_or(2000000),
+            max_index: params.get("max").unwrap_or(4000000),
+        })
+    }
+
+    /// The example asks about line 10 and a 20x20 area instead.
+    fn example_params() -> Params {
+        Params::default().with("row", 10).with("max", 20)
+    }
+
+    fn validate(data: &str) -> Vec<Error> {
+        validate::check_lines(1, data.lines(), validate::parses::<DataItem>)
+    }
+
+    /// `count` random sensors that miss the distress beacon, then eight far
+    /// outside the search area, from 0 to `extent` on both axes, which
+    /// reach every other position of it: those on the axes through the
+    /// beacon cover the positions further along their axis than across it,
+    /// and those on the diagonals cover the diagonals.
+    fn generate(rng: &mut Rng, size: Size) -> crate::Result<String> {
+        let max = size.extent as i64;
+        let distress = point2(rng.range(0..=max), rng.range(0..=max));
+        let margin = max / 4 + 1;
+        let mut items = Vec::new();
+        for _ in 0..size.count {
+            let s = point2(
+                rng.range(-margin..=max + margin),
+                rng.range(-margin..=max + margin),
+            );
+            items.extend(generate_sensor(rng, s, distress));
+        }
+        let far = 2 * max + 2;
+        for (dx, dy) in [
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ] {
+            let s = point2(distress.x + dx * far, distress.y + dy * far);
+            items.extend(generate_sensor(rng, s, distress));
+        }
+        rng.shuffle(&mut items);
+        let mut data = String::new();
+        for item in items {
+            data += &format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                item.s.x, item.s.y, item.b.x, item.b.y
+            );
+        }
+        Ok(data)
+    }
+
+    fn generated_params(size: Size) -> Params {
+        let max = size.extent as i64;
+        Params::default().with("row", max / 2).with("max", max)
+    }
+
+    fn part1(sensors: &Self::Input) -> crate::Result<u64> {
+        Ok(get_coverage_count(&sensors.items, sensors.line_index))
+    }
+
+    fn part2(sensors: &Self::Input) -> crate::Result<u64> {
+        let mut found = None;
+        'lines: for line_index in 0..=sensors.max_index {
+            if let Some(x) = find_not_covered(line_index, sensors.max_index, &sensors.items) {
+                found = Some((x, line_index));
+                break 'lines;
+            }
+        }
+        let (x, y) = found.ok_or_else(|| Error::structure("no uncovered position found"))?;
+        Ok(x as u64 * 4000000 + y as u64)
+    }
+
+    /// Both parts show the search area filling up sensor by sensor, which
+    /// is only practical for the example.
+    fn visualise(sensors: &Self::Input, _part: Part, frames: &mut dyn Frames) -> crate::Result<()> {
+        if sensors.max_index > MAX_DRAWN_SIZE {
+            return Err(Error::structure("the search area is too large to draw"));
+        }
+        for count in 0..=sensors.items.len() {
+            frames.step(&|| draw(sensors, count).into())?;
+        }
+        frames.end(&|| draw(sensors, sensors.items.len()).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day15;
+    use crate::generate::Rng;
+    use crate::generate::Size;
+    use crate::geom::point2;
+    use crate::Solution;
+
+    #[test]
+    fn test_generate_leaves_one_position_uncovered() {
+        for seed in 0..5 {
+            let size = Size {
+                count: 10,
+                extent: 30,
+            };
+            let data = Day15::generate(&mut Rng::new(seed), size).unwrap();
+            let sensors = Day15::parse_with(&data, &Day15::generated_params(size)).unwrap();
+            let uncovered = (0..=sensors.max_index)
+                .flat_map(|y| (0..=sensors.max_index).map(move |x| point2(x, y)))
+                .filter(|&p| {
+                    sensors
+                        .items
+                        .iter()
+                        .all(|item| item.s.manhattan(p) > item.distance())
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(uncovered.len(), 1, "seed {}", seed);
+            let answer = uncovered[0].x as u64 * 4000000 + uncovered[0].y as u64;
+            assert_eq!(Day15::part2(&sensors).unwrap(), answer);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error type shared by all input loaders and puzzle parsers.
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read or written.
+    Io { path: String, source: io::Error },
+    /// A request to the puzzle website failed.
+    Http { url: String, message: String },
+    /// A token that should be a number is not.
+    ParseInt(ParseIntError),
+    /// A token at the given 1-based column does not fit the expected format.
+    /// An empty `found` means the line ended too early.
+    UnexpectedToken {
+        column: usize,
+        found: String,
+        expected: String,
+    },
+    /// The input is well-formed token by token but does not describe a valid puzzle.
+    Structure(String),
+    /// Any of the above, located at a 1-based line of the input.
+    Line {
+        line: usize,
+        text: String,
+        source: Box<Error>,
+    },
+    /// Any of the above, raised while parsing the named input file.
+    File { path: String, source: Box<Error> },
+    /// A solver panicked, with the panic message and where it happened if
+    /// the hook of `runner::install_panic_hook` recorded it.
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+}
+
+impl Error {
+    pub fn unexpected(column: usize, found: &str, expected: &str) -> Self {
+        Error::UnexpectedToken {
+            column,
+            found: found.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Builds an `UnexpectedToken` error for `token`, which must be a
+    /// sub-slice of `line`; the column is derived from its position.
+    pub fn unexpected_in(line: &str, token: &str, expected: &str) -> Self {
+        Error::unexpected(crate::slice_offset(line, token) + 1, token, expected)
+    }
+
+    /// Builds an `UnexpectedToken` error pointing just past the end of `line`.
+    pub fn end_of_line(line: &str, expected: &str) -> Self {
+        Error::unexpected(line.len() + 1, "", expected)
+    }
+
+    pub fn structure(message: impl Into<String>) -> Self {
+        Error::Structure(message.into())
+    }
+
+    /// Moves the reported column right by `offset`, for errors produced
+    /// while parsing a slice that starts `offset` bytes into the line.
+    pub fn offset(self, offset: usize) -> Self {
+        match self {
+            Error::UnexpectedToken {
+                column,
+                found,
+                expected,
+            } => Error::UnexpectedToken {
+                column: column + offset,
+                found,
+                expected,
+            },
+            e => e,
+        }
+    }
+
+    pub fn at_line(self, line: usize, text: &str) -> Self {
+        Error::Line {
+            line,
+            text: text.to_string(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Moves the reported line down by `offset`, for errors produced while
+    /// parsing a section that starts `offset` lines into the input.
+    pub fn offset_lines(self, offset: usize) -> Self {
+        match self {
+            Error::Line { line, text, source } => Error::Line {
+                line: line + offset,
+                text,
+                source,
+            },
+            e => e,
+        }
+    }
+
+    /// The 1-based line and column the error is located at, if known.
+    pub fn position(&self) -> (Option<usize>, Option<usize>) {
+        match self {
+            Error::UnexpectedToken { column, .. } => (None, Some(*column)),
+            Error::Line { line, source, .. } => (Some(*line), source.position().1),
+            Error::File { source, .. } => source.position(),
+            _ => (None, None),
+        }
+    }
+
+    /// Attaches the input file name; I/O errors already carry it.
+    pub fn in_file(self, path: &str) -> Self {
+        match self {
+            e @ Error::Io { .. } => e,
+            e => Error::File {
+                path: path.to_string(),
+                source: Box::new(e),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path, source),
+            Error::Http { url, message } => write!(f, "{}: {}", url, message),
+            Error::ParseInt(e) => write!(f, "{}", e),
+            Error::UnexpectedToken {
+                column,
+                found,
+                expected,
+            } => {
+                if found.is_empty() {
+                    write!(
+                        f,
+                        "column {}: expected {}, found end of line",
+                        column, expected
+                    )
+                } else {
+                    write!(
+                        f,
+                        "column {}: expected {}, found {:?}",
+                        column, expected, found
+                    )
+                }
+            }
+            Error::Structure(message) => write!(f, "{}", message),
+            Error::Panicked {
+                message,
+                location: Some(location),
+            } => write!(f, "panicked at {}: {}", location, message),
+            Error::Panicked {
+                message,
+                location: None,
+            } => write!(f, "panicked: {}", message),
+            Error::Line { line, text, source } => {
+                write!(f, "line {}: {} in {:?}", line, source, text)
+            }
+            Error::File { path, source } => match source.as_ref() {
+                Error::Line { line, text, source } => {
+                    write!(f, "{}:{}: {} in {:?}", path, line, source, text)
+                }
+                e => write!(f, "{}: {}", path, e),
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::ParseInt(e) => Some(e),
+            Error::Line { source, .. } => Some(source.as_ref()),
+            Error::File { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::ParseInt(e)
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Error;
+
+    #[test]
+    fn test_unexpected_in() {
+        let line = "move 1 form 2 to 3";
+        let err = Error::unexpected_in(line, &line[7..11], "\"from\"");
+        assert_eq!(
+            err.to_string(),
+            "column 8: expected \"from\", found \"form\""
+        );
+    }
+
+    #[test]
+    fn test_end_of_line() {
+        let err = Error::end_of_line("addx", "a number").offset(2);
+        assert_eq!(
+            err.to_string(),
+            "column 7: expected a number, found end of line"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::grid::Grid;
+use crate::grid::Pos;
+use crate::image::Format;
+use crate::image::Image;
+use crate::image::Palette;
+use crate::Error;
+use crate::Result;
+use std::fs;
+use std::path::PathBuf;
+
+/// One state of a simulation drawn as characters, such as the output of
+/// `SparseGrid::to_grid`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub picture: Grid<char>,
+    /// The cell of the picture worth keeping in view, if any.
+    pub focus: Option<Pos>,
+}
+
+/// Receives the states of a simulation. `draw` is only called for the
+/// states the receiver keeps, so simulations can report every step cheaply.
+pub trait Frames {
+    /// Reports the state after one step.
+    fn step(&mut self, draw: &dyn Fn() -> Frame) -> Result<()>;
+
+    /// Reports the final state.
+    fn end(&mut self, draw: &dyn Fn() -> Frame) -> Result<()> {
+        self.step(draw)
+    }
+}
+
+impl Frame {
+    pub fn with_focus(picture: Grid<char>, focus: Pos) -> Self {
+        Frame {
+            picture,
+            focus: Some(focus),
+        }
+    }
+}
+
+impl From<Grid<char>> for Frame {
+    fn from(picture: Grid<char>) -> Self {
+        Frame {
+            picture,
+            focus: None,
+        }
+    }
+}
+
+/// Ignores every frame; used when solving without a visualisation.
+pub struct Discard;
+
+impl Frames for Discard {
+    fn step(&mut self, _draw: &dyn Fn() -> Frame) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes every `every`th step, and the final state, as numbered images
+/// `frame-00000.png`, `frame-00001.png` and so on in a directory, which is
+/// created with the first frame.
+pub struct FrameDir {
+    dir: PathBuf,
+    format: Format,
+    palette: Palette,
+    scale: usize,
+    every: usize,
+    steps: usize,
+    written: usize,
+}
+
+impl FrameDir {
+    pub fn new(dir: PathBuf, format: Format, palette: Palette, scale: usize, every: usize) -> Self {
+        FrameDir {
+            dir,
+            format,
+            palette,
+            scale: scale.max(1),
+            every: every.max(1),
+            steps: 0,
+            written: 0,
+        }
+    }
+
+    /// The number of images written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    fn write(&mut self, frame: &Frame) -> Result<()> {
+        if self.written == 0 {
+            fs::create_dir_all(&self.dir).map_err(|source| Error::Io {
+                path: self.dir.display().to_string(),
+                source,
+            })?;
+        }
+        let name = format!("frame-{:05}.{}", self.written, self.format.extension());
+        Image::from_grid(&frame.picture, &self.palette, self.scale)
+            .save(&self.dir.join(name), self.format)?;
+        self.written += 1;
+        Ok(())
+    }
+}
+
+impl Frames for FrameDir {
+    fn step(&mut self, draw: &dyn Fn() -> Frame) -> Result<()> {
+        let due = self.steps.is_multiple_of(self.every);
+        self.steps += 1;
+        if due {
+            self.write(&draw())?;
+        }
+        Ok(())
+    }
+
+    fn end(&mut self, draw: &dyn Fn() -> Frame) -> Result<()> {
+        // The last step was already written unless it fell between frames.
+        if self.steps == 0 || !(self.steps - 1).is_multiple_of(self.every) {
+            self.write(&draw())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FrameDir;
+    use super::Frames;
+    use crate::grid::Grid;
+    use crate::image::Format;
+    use crate::image::Palette;
+    use crate::site::mock::temp_dir;
+
+    #[test]
+    fn test_frame_dir() {
+        let dir = temp_dir("frames");
+        let mut frames = FrameDir::new(dir.join("out"), Format::Ppm, Palette::default(), 1, 3);
+        let draw = || Grid::new(2, 1, '#').into();
+        for _ in 0..7 {
+            frames.step(&draw).unwrap();
+        }
+        frames.end(&draw).unwrap();
+        // Steps 0, 3 and 6, then nothing more since step 6 was the last.
+        assert_eq!(frames.written(), 3);
+        let written = std::fs::read(dir.join("out/frame-00002.ppm")).unwrap();
+        assert!(written.starts_with(b"P6\n2 1\n255\n"));
+    }
+}
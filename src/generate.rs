@@ -0,0 +1,137 @@
+use std::ops::RangeInclusive;
+
+/// A small deterministic random number generator (SplitMix64), so that a
+/// seed always gives the same generated input on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+/// How big a generated input should be. Each day documents how it reads
+/// these: `count` is the number of records, such as elves, moves or
+/// packet pairs, and `extent` the spread within them, such as the side
+/// of a grid or the number of stacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    pub count: usize,
+    pub extent: usize,
+}
+
+impl Default for Size {
+    fn default() -> Self {
+        Size {
+            count: 100,
+            extent: 10,
+        }
+    }
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+        let span = end.abs_diff(start) as u128 + 1;
+        start.wrapping_add(((self.next_u64() as u128 * span) >> 64) as i64)
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A word of `1..=max_len` letters from `letters`.
+    pub fn word(&mut self, letters: &str, max_len: usize) -> String {
+        let letters = letters.chars().collect::<Vec<_>>();
+        let len = 1 + self.below(max_len.max(1));
+        (0..len).map(|_| *self.pick(&letters)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Rng;
+    use super::Size;
+    use crate::days;
+    use crate::input::Source;
+    use crate::Part;
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert_eq!(
+            (0..10).map(|_| a.next_u64()).collect::<Vec<_>>(),
+            (0..10).map(|_| b.next_u64()).collect::<Vec<_>>()
+        );
+        for _ in 0..1000 {
+            assert!(a.below(3) < 3);
+            assert!((-2..=2).contains(&a.range(-2..=2)));
+        }
+        let mut items = (0..20).collect::<Vec<_>>();
+        a.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    /// Every generated input passes validation and solves without errors,
+    /// including the smallest sizes.
+    #[test]
+    fn test_generated_inputs_are_valid() {
+        let sizes = [
+            Size {
+                count: 1,
+                extent: 1,
+            },
+            Size {
+                count: 20,
+                extent: 6,
+            },
+        ];
+        for day in days::ALL {
+            for seed in 0..5 {
+                for size in sizes {
+                    let input = day.generate(&mut Rng::new(seed), size).unwrap();
+                    let context = format!(
+                        "day {} seed {} {:?}:\n{}",
+                        day.number, seed, size, input.data
+                    );
+                    // As in `aoc generate <day> | aoc validate <day> -`.
+                    let errors = day.validate(&input.data, &Source::Stdin);
+                    assert!(errors.is_empty(), "{:?} in {}", errors, context);
+                    let input = day.parse(&input.data, &input.params).expect(&context);
+                    for part in Part::ALL {
+                        day.solve(input.as_ref(), part).expect(&context);
+                    }
+                }
+            }
+        }
+    }
+}
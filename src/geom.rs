@@ -0,0 +1,308 @@
+use crate::Error;
+use std::fmt;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+use std::str::FromStr;
+
+/// A point or vector on the plane, with `y` growing down like the rows of
+/// a puzzle map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// One of the four orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the eight orthogonal and diagonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+pub const fn point2(x: i64, y: i64) -> Point2 {
+    Point2 { x, y }
+}
+
+pub const fn point3(x: i64, y: i64, z: i64) -> Point3 {
+    Point3 { x, y, z }
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = point2(0, 0);
+
+    pub fn signum(self) -> Self {
+        point2(self.x.signum(), self.y.signum())
+    }
+
+    pub fn abs(self) -> Self {
+        point2(self.x.abs(), self.y.abs())
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        let d = (self - other).abs();
+        d.x + d.y
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        let d = (self - other).abs();
+        d.x.max(d.y)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = point3(0, 0, 0);
+
+    pub fn signum(self) -> Self {
+        point3(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn abs(self) -> Self {
+        point3(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        let d = (self - other).abs();
+        d.x + d.y + d.z
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        let d = (self - other).abs();
+        d.x.max(d.y).max(d.z)
+    }
+}
+
+/// Implements the arithmetic operators of a point type component-wise.
+macro_rules! impl_ops {
+    ($t:ident { $($c:ident),+ }) => {
+        impl Add for $t {
+            type Output = $t;
+
+            fn add(self, other: $t) -> $t {
+                $t { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+
+            fn sub(self, other: $t) -> $t {
+                $t { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Mul<i64> for $t {
+            type Output = $t;
+
+            fn mul(self, k: i64) -> $t {
+                $t { $($c: self.$c * k),+ }
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+
+            fn neg(self) -> $t {
+                $t { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, other: $t) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, other: $t) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        point2(x, y)
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// The unit step in this direction.
+    pub fn step(self) -> Point2 {
+        match self {
+            Direction4::Up => point2(0, -1),
+            Direction4::Right => point2(1, 0),
+            Direction4::Down => point2(0, 1),
+            Direction4::Left => point2(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Accepts both `U`, `D`, `L`, `R` and `^`, `v`, `<`, `>`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Direction4::Up),
+            'R' | '>' => Some(Direction4::Right),
+            'D' | 'v' => Some(Direction4::Down),
+            'L' | '<' => Some(Direction4::Left),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Direction4::from_char), chars.next()) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err(Error::unexpected(1, s, "U, D, L, R, ^, v, < or >")),
+        }
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// The unit step in this direction; diagonal steps move along both axes.
+    pub fn step(self) -> Point2 {
+        match self {
+            Direction8::Up => point2(0, -1),
+            Direction8::UpRight => point2(1, -1),
+            Direction8::Right => point2(1, 0),
+            Direction8::DownRight => point2(1, 1),
+            Direction8::Down => point2(0, 1),
+            Direction8::DownLeft => point2(-1, 1),
+            Direction8::Left => point2(-1, 0),
+            Direction8::UpLeft => point2(-1, -1),
+        }
+    }
+
+    /// Turns by an eighth of a full turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by an eighth of a full turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(d: Direction4) -> Self {
+        Direction8::ALL[d as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::point2;
+    use super::point3;
+    use super::Direction4;
+    use super::Direction8;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_points() {
+        let a = point2(1, -2);
+        let b = point2(-3, 5);
+        assert_eq!(a + b, point2(-2, 3));
+        assert_eq!(a - b, point2(4, -7));
+        assert_eq!(-a * 2, point2(-2, 4));
+        assert_eq!((a - b).signum(), point2(1, -1));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(point3(1, 2, 3).manhattan(point3(0, 0, 0)), 6);
+        assert_eq!(point3(1, 2, 3).chebyshev(point3(0, 0, 0)), 3);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction4::from_str("U").unwrap(), Direction4::Up);
+        assert_eq!(Direction4::from_str(">").unwrap(), Direction4::Right);
+        assert_eq!(
+            Direction4::from_str("X").unwrap_err().to_string(),
+            "column 1: expected U, D, L, R, ^, v, < or >, found \"X\""
+        );
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Down.opposite(), Direction4::Up);
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::Down);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::DownRight.opposite().step(), point2(-1, -1));
+    }
+}
@@ -0,0 +1,365 @@
+use crate::geom::Direction4;
+use crate::geom::Direction8;
+use crate::geom::Point2;
+use crate::Error;
+use crate::Result;
+use std::fmt;
+use std::ops::Index;
+use std::ops::IndexMut;
+
+/// A cell position, `(x, y)` with `x` growing right and `y` growing down.
+pub type Pos = (usize, usize);
+
+/// A rectangular map of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Iterator over the positions from a cell to the edge of a grid, in steps
+/// of a fixed direction. Created by `Grid::line` and `Grid::ray`.
+#[derive(Debug, Clone)]
+pub struct Ray {
+    pos: Option<Pos>,
+    step: Point2,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from cells listed row by row.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Result<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return Err(Error::structure(format!(
+                "{} cells do not make rows of {}",
+                cells.len(),
+                width
+            )));
+        }
+        Ok(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a map with one character per cell and one line per row,
+    /// converting characters with `cell`. Errors point at the offending
+    /// character; `expected` describes what `cell` accepts.
+    pub fn parse(data: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self> {
+        let mut lines = data.lines().collect::<Vec<_>>();
+        while lines.last() == Some(&"") {
+            lines.pop();
+        }
+        let width = lines
+            .first()
+            .ok_or_else(|| Error::structure("empty grid"))?
+            .chars()
+            .count();
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let mut count = 0;
+            for (column, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    Error::unexpected(column + 1, &c.to_string(), expected).at_line(y + 1, line)
+                })?;
+                cells.push(value);
+                count += 1;
+            }
+            if count != width {
+                let message = format!("expected a row of {} cells, found {}", width, count);
+                return Err(Error::structure(message).at_line(y + 1, line));
+            }
+        }
+        Grid::from_vec(width, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(move |i| &mut self.cells[i])
+    }
+
+    /// The position `step` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, pos: Pos, step: Point2) -> Option<Pos> {
+        offset_within(pos, step, self.width, self.height)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The positions from `start` to the edge in direction `step`,
+    /// `start` included.
+    pub fn line(&self, start: Pos, step: Point2) -> Ray {
+        Ray {
+            pos: Some(start).filter(|&pos| self.contains(pos)),
+            step,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The positions seen looking from `from` in direction `step`, up to
+    /// the edge and without `from` itself.
+    pub fn ray(&self, from: Pos, step: Point2) -> Ray {
+        let mut ray = self.line(from, step);
+        ray.next();
+        ray
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction4::ALL
+            .iter()
+            .filter_map(move |d| self.offset(pos, d.step()))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .iter()
+            .filter_map(move |d| self.offset(pos, d.step()))
+    }
+
+    /// The orthogonal neighbours of `pos` that can be entered from it, as
+    /// decided by `can_move(from, to)` on their cells. Suits the successor
+    /// functions of `search` for mazes.
+    pub fn moves<'a>(
+        &'a self,
+        pos: Pos,
+        can_move: impl Fn(&T, &T) -> bool + 'a,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        self.neighbours4(pos)
+            .filter(move |&next| can_move(&self[pos], &self[next]))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rebuild(height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rebuild(self.height, width, |(x, y)| (width - 1 - y, x))
+    }
+
+    /// Builds a `width` x `height` grid whose cell at `pos` is the cell of
+    /// this grid at `source(pos)`.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn index_of(&self, (x, y): Pos) -> Option<usize> {
+        if self.contains((x, y)) {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.index_of(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{:?} is outside of a {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        match self.index_of(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{:?} is outside of a {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for Ray {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.pos?;
+        self.pos = offset_within(pos, self.step, self.width, self.height);
+        Some(pos)
+    }
+}
+
+/// The position `step` away from `pos`, if it is inside a `width` x
+/// `height` grid.
+fn offset_within((x, y): Pos, step: Point2, width: usize, height: usize) -> Option<Pos> {
+    let x = usize::try_from(x as i64 + step.x).ok()?;
+    let y = usize::try_from(y as i64 + step.y).ok()?;
+    Some((x, y)).filter(|&(x, y)| x < width && y < height)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid;
+    use crate::geom::Direction4;
+    use crate::geom::Direction8;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", "a letter", |c| {
+            Some(c).filter(char::is_ascii_lowercase)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let err = Grid::parse("ab\na1\n", "a letter", |c| {
+            Some(c).filter(char::is_ascii_lowercase)
+        });
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "line 2: column 2: expected a letter, found \"1\" in \"a1\""
+        );
+        let err = Grid::parse("ab\nabc\n", "a letter", Some);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "line 2: expected a row of 2 cells, found 3 in \"abc\""
+        );
+    }
+
+    #[test]
+    fn test_rays() {
+        let grid = sample();
+        assert_eq!(
+            grid.line((2, 0), Direction4::Left.step())
+                .collect::<Vec<_>>(),
+            vec![(2, 0), (1, 0), (0, 0)]
+        );
+        assert_eq!(
+            grid.ray((1, 1), Direction4::Up.step()).collect::<Vec<_>>(),
+            vec![(1, 0)]
+        );
+        assert_eq!(grid.ray((1, 1), Direction4::Down.step()).count(), 0);
+        assert_eq!(
+            grid.line((0, 0), Direction8::DownRight.step())
+                .collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
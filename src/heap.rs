@@ -0,0 +1,157 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::fmt;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+/// Whether the `count-allocations` feature installed `Counting` as the
+/// global allocator.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+/// Set while `measure` runs, since a second measurement would reset `PEAK`
+/// under the first one.
+static MEASURING: AtomicBool = AtomicBool::new(false);
+
+/// The system allocator, counting the bytes it hands out.
+pub struct Counting;
+
+/// The heap usage of the whole process while `measure` ran its closure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// The most bytes in use at once, above what was in use at the start.
+    pub peak_bytes: usize,
+    pub allocated_bytes: usize,
+    /// Allocations, counting each reallocation as one.
+    pub allocations: usize,
+}
+
+fn record(size: usize) {
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    TOTAL.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record(new_size);
+        }
+        new_ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+/// Clears `MEASURING` when a measurement ends, even by a panic.
+struct Measuring;
+
+impl Drop for Measuring {
+    fn drop(&mut self) {
+        MEASURING.store(false, Ordering::Release);
+    }
+}
+
+/// Runs `f` and returns the heap usage meanwhile, or `None` without the
+/// `count-allocations` feature. The counters are shared by all threads, so
+/// only one measurement runs at a time: `None` is also returned while
+/// another thread measures. Allocations of threads that are not measuring
+/// still count, which is why the command line refuses `--parallel` with
+/// the feature.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !ENABLED || MEASURING.swap(true, Ordering::Acquire) {
+        return (f(), None);
+    }
+    let _measuring = Measuring;
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+    let total = TOTAL.load(Ordering::Relaxed);
+    let count = COUNT.load(Ordering::Relaxed);
+    let value = f();
+    let usage = Usage {
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(start),
+        allocated_bytes: TOTAL.load(Ordering::Relaxed) - total,
+        allocations: COUNT.load(Ordering::Relaxed) - count,
+    };
+    (value, Some(usage))
+}
+
+/// Formats a byte count with a binary unit, such as `1.5 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocations of {}",
+            format_bytes(self.peak_bytes),
+            self.allocations,
+            format_bytes(self.allocated_bytes)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::format_bytes;
+    use super::measure;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_measure() {
+        // Another test may be measuring meanwhile, so `usage` can be `None`
+        // even with the feature.
+        let ((len, inner), usage) = measure(|| measure(|| vec![0u8; 4096].len()));
+        assert_eq!(len, 4096);
+        assert_eq!(inner, None);
+        assert!(super::ENABLED || usage.is_none());
+        if let Some(usage) = usage {
+            assert!(usage.allocations >= 1 && usage.allocated_bytes >= 4096);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::grid::Grid;
+use crate::Error;
+use crate::Result;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// The colours used to draw the characters of a frame. Dots and spaces are
+/// background; characters without a colour of their own get the foreground.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colours: HashMap<char, Rgb>,
+    background: Rgb,
+    foreground: Rgb,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+}
+
+/// An RGB picture stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl FromStr for Rgb {
+    type Err = Error;
+
+    /// Parses six hexadecimal digits, `rrggbb`.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let channel = |i: usize| s.get(i..i + 2).and_then(|c| u8::from_str_radix(c, 16).ok());
+        match (s.len(), channel(0), channel(2), channel(4)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Rgb(r, g, b)),
+            _ => Err(Error::unexpected(1, s, "a colour as rrggbb")),
+        }
+    }
+}
+
+impl Palette {
+    pub fn new(background: Rgb, foreground: Rgb) -> Self {
+        Palette {
+            colours: HashMap::new(),
+            background,
+            foreground,
+        }
+    }
+
+    pub fn with(mut self, c: char, colour: Rgb) -> Self {
+        self.colours.insert(c, colour);
+        self
+    }
+
+    pub fn colour(&self, c: char) -> Rgb {
+        match self.colours.get(&c) {
+            Some(&colour) => colour,
+            None if c == '.' || c == ' ' => self.background,
+            None => self.foreground,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::new(Rgb(16, 16, 32), Rgb(200, 200, 200))
+            .with('#', Rgb(240, 240, 240))
+            .with('o', Rgb(230, 190, 90))
+            .with('H', Rgb(220, 60, 60))
+            .with('T', Rgb(90, 200, 90))
+            .with('S', Rgb(80, 160, 240))
+            .with('B', Rgb(240, 80, 80))
+    }
+}
+
+impl FromStr for Palette {
+    type Err = Error;
+
+    /// Parses comma-separated `<char>=rrggbb` entries, which override the
+    /// colours of the default palette.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut palette = Palette::default();
+        for entry in s.split(',') {
+            let mut chars = entry.chars();
+            let c = chars.next();
+            match (c, chars.next()) {
+                (Some(c), Some('=')) => {
+                    let offset = entry.len() - chars.as_str().len();
+                    let colour = chars
+                        .as_str()
+                        .parse::<Rgb>()
+                        .map_err(|e| e.offset(offset))?;
+                    palette = palette.with(c, colour);
+                }
+                _ => return Err(Error::unexpected(1, entry, "<char>=rrggbb")),
+            }
+        }
+        Ok(palette)
+    }
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            _ => Err(Error::unexpected(1, s, "ppm or png")),
+        }
+    }
+}
+
+impl Image {
+    /// Draws every character of `frame` as a square of `scale` pixels.
+    pub fn from_grid(frame: &Grid<char>, palette: &Palette, scale: usize) -> Self {
+        let (width, height) = (frame.width() * scale, frame.height() * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                pixels.push(palette.colour(frame[(x / scale, y / scale)]));
+            }
+        }
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn encode(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Ppm => self.to_ppm(),
+            Format::Png => self.to_png(),
+        }
+    }
+
+    pub fn save(&self, path: &Path, format: Format) -> Result<()> {
+        fs::write(path, self.encode(format)).map_err(|source| Error::Io {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    /// Binary PPM: a short text header followed by the raw RGB bytes.
+    fn to_ppm(&self) -> Vec<u8> {
+        let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        data.extend(self.pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+        data
+    }
+
+    /// An 8-bit RGB PNG without filtering.
+    fn to_png(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // Bit depth 8, colour type RGB, default compression, filter and no
+        // interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let bytes = row.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]);
+            let line = std::iter::once(0).chain(bytes).collect::<Vec<_>>();
+            encoder.write_all(&line).expect("writing to memory");
+        }
+        let pixels = encoder.finish().expect("writing to memory");
+
+        let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut data, b"IHDR", &header);
+        png_chunk(&mut data, b"IDAT", &pixels);
+        png_chunk(&mut data, b"IEND", &[]);
+        data
+    }
+}
+
+fn png_chunk(data: &mut Vec<u8>, kind: &[u8; 4], content: &[u8]) {
+    data.extend((content.len() as u32).to_be_bytes());
+    data.extend(kind);
+    data.extend(content);
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(content);
+    data.extend(crc.finalize().to_be_bytes());
+}
+
+#[cfg(test)]
+mod test {
+    use super::Format;
+    use super::Image;
+    use super::Palette;
+    use super::Rgb;
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_palette() {
+        let palette = "#=ff8000,x=000001".parse::<Palette>().unwrap();
+        assert_eq!(palette.colour('#'), Rgb(255, 128, 0));
+        assert_eq!(palette.colour('x'), Rgb(0, 0, 1));
+        assert_eq!(palette.colour('.'), Palette::default().colour(' '));
+        assert_eq!(
+            "#=ff80z0".parse::<Palette>().unwrap_err().to_string(),
+            "column 3: expected a colour as rrggbb, found \"ff80z0\""
+        );
+    }
+
+    #[test]
+    fn test_image() {
+        let frame = Grid::parse("#.\n.#\n", "a pixel", Some).unwrap();
+        let palette = Palette::new(Rgb(0, 0, 0), Rgb(1, 2, 3)).with('#', Rgb(9, 9, 9));
+        let image = Image::from_grid(&frame, &palette, 2);
+        assert_eq!((image.width(), image.height()), (4, 4));
+
+        let ppm = image.encode(Format::Ppm);
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        assert_eq!(&ppm[11..17], &[9, 9, 9, 9, 9, 9]);
+        assert_eq!(&ppm[17..20], &[0, 0, 0]);
+
+        let png = image.encode(Format::Png);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x04\0\0\0\x04"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::days;
+use crate::params::Params;
+use crate::stream;
+use crate::stream::Reader;
+use crate::Error;
+use crate::Result;
+use std::io::Cursor;
+use std::path::Path;
+use std::path::PathBuf;
+
+pub const DATA_DIR: &str = "data";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `data/dayNN.txt`.
+    Default,
+    /// The example from the puzzle text, `data/dayNN-test.txt` or `data/dayNN_test.txt`.
+    Example,
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+/// Puzzle input text together with a name to use in error messages.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+    pub data: String,
+    /// The parameters given beside the input: those of the example for the
+    /// example file, none otherwise.
+    pub params: Params,
+}
+
+impl Input {
+    /// Reads the rest of `reader` whole.
+    pub fn read(reader: Reader, params: Params) -> Result<Self> {
+        Ok(Input {
+            name: reader.name().to_string(),
+            data: reader.read_to_string()?,
+            params,
+        })
+    }
+
+    /// The input with `overrides` replacing or adding to its parameters.
+    pub fn with_params(mut self, overrides: &Params) -> Self {
+        self.params = self.params.overridden_by(overrides);
+        self
+    }
+
+    /// The 64-bit FNV-1a hash of the data, to tell inputs apart in reports.
+    pub fn hash(&self) -> u64 {
+        hash_bytes(HASH_START, self.data.as_bytes())
+    }
+}
+
+impl Source {
+    /// Interprets a command line argument: `-` is stdin, anything else a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self, day: u32) -> Result<Input> {
+        Input::read(self.open(day)?, self.params(day))
+    }
+
+    /// Opens the input for reading as it goes. Gzip-compressed files and
+    /// stdin are decompressed on the fly, see `stream::open`.
+    pub fn open(&self, day: u32) -> Result<Reader> {
+        match self {
+            Source::Default => stream::open(&default_path(day)),
+            Source::Example => stream::open(&example_path(day)?),
+            Source::File(path) => stream::open(path),
+            Source::Stdin => stream::open(Path::new("-")),
+            Source::Text(data) => Ok(Reader::new(
+                Box::new(Cursor::new(data.clone().into_bytes())),
+                "<text>",
+            )),
+        }
+    }
+
+    /// The parameters given beside the input: those of the example if it
+    /// is the example file, whichever way it was named, none otherwise.
+    pub fn params(&self, day: u32) -> Params {
+        let is_example = match self {
+            Source::Example => true,
+            Source::File(path) => example_candidates(day)
+                .iter()
+                .any(|candidate| same_file(candidate, path)),
+            _ => false,
+        };
+        match days::find(day) {
+            Some(day) if is_example => day.example_params(),
+            _ => Params::default(),
+        }
+    }
+}
+
+/// The hash of no data, see `Input::hash`.
+pub const HASH_START: u64 = 0xcbf29ce484222325;
+
+/// Continues `hash` with `bytes`, see `Input::hash`.
+pub fn hash_bytes(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+pub fn default_path(day: u32) -> PathBuf {
+    Path::new(DATA_DIR).join(format!("day{:02}.txt", day))
+}
+
+/// Finds the example input of `day`; both `dayNN-test.txt` and
+/// `dayNN_test.txt` are in use.
+pub fn example_path(day: u32) -> Result<PathBuf> {
+    let candidates = example_candidates(day);
+    candidates
+        .iter()
+        .find(|p| p.is_file())
+        .cloned()
+        .ok_or_else(|| {
+            Error::structure(format!(
+                "no example input for day {}, tried {}",
+                day,
+                candidates
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" and ")
+            ))
+        })
+}
+
+fn example_candidates(day: u32) -> [PathBuf; 2] {
+    [
+        Path::new(DATA_DIR).join(format!("day{:02}-test.txt", day)),
+        Path::new(DATA_DIR).join(format!("day{:02}_test.txt", day)),
+    ]
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::example_path;
+    use super::Input;
+    use super::Source;
+    use crate::params::Params;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_example_path() {
+        assert_eq!(
+            example_path(10).unwrap(),
+            PathBuf::from("data/day10_test.txt")
+        );
+        assert_eq!(
+            example_path(14).unwrap(),
+            PathBuf::from("data/day14-test.txt")
+        );
+        assert!(example_path(1).is_err());
+    }
+
+    #[test]
+    fn test_hash() {
+        let input = |data: &str| Input {
+            name: String::new(),
+            data: data.to_string(),
+            params: Params::default(),
+        };
+        assert_eq!(input("").hash(), 0xcbf29ce484222325);
+        assert_eq!(input("a").hash(), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("x.txt"),
+            Source::File(PathBuf::from("x.txt"))
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt;
+
+/// A contiguous range of integers, stored half-open as `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+/// A set of integers kept as sorted, disjoint and non-adjacent intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl Interval {
+    /// `first..=last`; empty if `last < first`, which callers rely on for
+    /// ranges that shrink to nothing. Check the order first where a
+    /// reversed range is an input error rather than an empty one.
+    pub fn inclusive(first: i64, last: i64) -> Self {
+        Interval {
+            start: first,
+            end: last.max(first - 1) + 1,
+        }
+    }
+
+    /// `start..end`; empty if `end <= start`.
+    pub fn exclusive(start: i64, end: i64) -> Self {
+        Interval {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    /// The largest value in the interval, which must not be empty.
+    pub fn last(&self) -> i64 {
+        self.end - 1
+    }
+
+    pub fn len(&self) -> u64 {
+        (self.end - self.start) as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    /// Whether every value of `other` is in this interval.
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether the intervals share at least one value.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::exclusive(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// The merged intervals, in increasing order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= x);
+        self.intervals.get(i).is_some_and(|i| i.contains(x))
+    }
+
+    /// Adds every value of `interval`, merging it with the intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Removes every value of `interval`.
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end <= interval.start);
+        let last = self.intervals.partition_point(|i| i.start < interval.end);
+        let mut rest = Vec::new();
+        if first < last {
+            let left = Interval::exclusive(self.intervals[first].start, interval.start);
+            let right = Interval::exclusive(interval.end, self.intervals[last - 1].end);
+            rest.extend([left, right].into_iter().filter(|i| !i.is_empty()));
+        }
+        self.intervals.splice(first..last, rest);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = self.clone();
+        for &interval in &other.intervals {
+            set.insert(interval);
+        }
+        set
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = self.clone();
+        for &interval in &other.intervals {
+            set.remove(interval);
+        }
+        set
+    }
+
+    /// The maximal runs of `within` that are not in the set.
+    pub fn gaps(&self, within: Interval) -> Vec<Interval> {
+        let mut gaps = Vec::new();
+        let mut start = within.start;
+        for interval in &self.intervals {
+            if interval.start >= within.end {
+                break;
+            }
+            if interval.start > start {
+                gaps.push(Interval::exclusive(start, interval.start));
+            }
+            start = start.max(interval.end);
+        }
+        if start < within.end {
+            gaps.push(Interval::exclusive(start, within.end));
+        }
+        gaps
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Interval;
+    use super::IntervalSet;
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::inclusive(2, 8);
+        let b = Interval::exclusive(3, 8);
+        assert_eq!((a.len(), b.len()), (7, 5));
+        assert!(a.contains(8) && !b.contains(8));
+        assert!(a.contains_interval(&b) && !b.contains_interval(&a));
+        assert!(a.overlaps(&Interval::inclusive(8, 9)));
+        assert!(!a.overlaps(&Interval::inclusive(9, 10)));
+        assert_eq!(
+            a.intersection(&Interval::inclusive(6, 12)),
+            Interval::inclusive(6, 8)
+        );
+        assert!(Interval::inclusive(3, 2).is_empty());
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let mut set = [(12, 14), (-2, 2), (3, 5), (20, 20)]
+            .into_iter()
+            .map(|(a, b)| Interval::inclusive(a, b))
+            .collect::<IntervalSet>();
+        assert_eq!(
+            set.intervals(),
+            &[
+                Interval::inclusive(-2, 5),
+                Interval::inclusive(12, 14),
+                Interval::inclusive(20, 20)
+            ]
+        );
+        assert_eq!(set.len(), 12);
+        assert!(set.contains(13) && !set.contains(15) && !set.contains(-3));
+        assert_eq!(
+            set.gaps(Interval::inclusive(0, 20)),
+            vec![Interval::inclusive(6, 11), Interval::inclusive(15, 19)]
+        );
+
+        set.remove(Interval::inclusive(4, 13));
+        assert_eq!(
+            set.intervals(),
+            &[
+                Interval::inclusive(-2, 3),
+                Interval::inclusive(14, 14),
+                Interval::inclusive(20, 20)
+            ]
+        );
+        let other = IntervalSet::from_iter([Interval::inclusive(4, 19)]);
+        assert_eq!(
+            set.union(&other).intervals(),
+            &[Interval::inclusive(-2, 20)]
+        );
+        assert_eq!(
+            set.difference(&other).intervals(),
+            &[Interval::inclusive(-2, 3), Interval::inclusive(20, 20)]
+        );
+    }
+}
@@ -1,41 +1,355 @@
-use std::fmt::Debug;
 use std::fs;
+use std::path::Path;
 use std::str::FromStr;
 use std::str::Lines;
 
-pub fn read_one_per_line<T>(file_path: &str) -> Vec<T>
+pub mod animation;
+pub mod answers;
+pub mod days;
+mod error;
+pub mod frames;
+pub mod generate;
+pub mod geom;
+pub mod grid;
+pub mod heap;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod ocr;
+pub mod params;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod site;
+mod solution;
+pub mod sparse_grid;
+pub mod stream;
+pub mod submit;
+pub mod template;
+pub mod validate;
+
+pub use error::Error;
+pub use error::Result;
+pub use solution::Day;
+pub use solution::Part;
+pub use solution::Solution;
+pub use solution::StreamedAnswers;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: heap::Counting = heap::Counting;
+
+pub fn read_one_per_line<T>(file_path: &str) -> Result<Vec<T>>
 where
     T: FromStr,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Into<Error>,
 {
-    let s = fs::read_to_string(file_path).unwrap();
-    s.lines()
-        .map(|line| line.parse::<T>().unwrap())
-        .collect::<Vec<T>>()
+    stream::lines(Path::new(file_path))?.parse().collect()
 }
 
-pub fn read_one_per_non_empty_line<T>(file_path: &str) -> Vec<T>
+pub fn read_one_per_non_empty_line<T>(file_path: &str) -> Result<Vec<T>>
 where
     T: FromStr,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Into<Error>,
+{
+    stream::lines(Path::new(file_path))?
+        .filter(|line| !matches!(line, Ok(line) if line.text.is_empty()))
+        .map(|line| line.and_then(|line| line.parse()))
+        .collect::<Result<_>>()
+        .map_err(|e| e.in_file(file_path))
+}
+
+pub fn parse_lines<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Error>,
+{
+    map_lines(1, s.lines())
+}
+
+pub fn parse_non_empty_lines<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Error>,
 {
-    let s = fs::read_to_string(file_path).unwrap();
     s.lines()
-        .filter(|line| line.len() > 0)
-        .map(|line| line.parse::<T>().unwrap())
-        .collect::<Vec<T>>()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_line(i + 1, line))
+        .collect()
 }
 
-pub fn map_lines<T>(lines: Lines) -> Vec<T>
+/// Parses every line of `lines`, numbering them from `first_line` so that
+/// errors point at the right place when `lines` is the tail of an input.
+pub fn map_lines<T>(first_line: usize, lines: Lines) -> Result<Vec<T>>
 where
     T: FromStr,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Into<Error>,
 {
     lines
-        .map(|line| line.parse::<T>().unwrap())
-        .collect::<Vec<T>>()
+        .enumerate()
+        .map(|(i, line)| parse_line(first_line + i, line))
+        .collect()
 }
 
-pub fn read_to_string(file_path: &str) -> String {
-    fs::read_to_string(file_path).unwrap()
+/// A run of lines of an input, delimited by blank lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Group<'a> {
+    /// The 1-based number of the first line of the group.
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+/// Iterator over the groups of non-blank lines of an input, created by `groups`.
+pub struct Groups<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+/// Splits `s` into groups of lines separated by one or more blank lines.
+pub fn groups(s: &str) -> Groups<'_> {
+    Groups { rest: s, line: 1 }
+}
+
+/// Parses every group of `s` with `T::from_str`. Line errors raised inside
+/// a group are renumbered to lines of `s`; other errors are reported at the
+/// first line of their group.
+pub fn parse_groups<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Error>,
+{
+    groups(s).map(|group| group.parse()).collect()
+}
+
+/// Splits `s` into a header, which is its first group, and a body made of
+/// everything after the blank lines following the header.
+pub fn sections(s: &str) -> Result<(Group<'_>, Group<'_>)> {
+    let mut groups = groups(s);
+    let header = groups
+        .next()
+        .ok_or_else(|| Error::structure("missing header"))?;
+    Ok((header, groups.remainder()))
+}
+
+impl<'a> Group<'a> {
+    pub fn lines(&self) -> Lines<'a> {
+        self.text.lines()
+    }
+
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Into<Error>,
+    {
+        self.text.parse::<T>().map_err(|e| match e.into() {
+            e @ Error::Line { .. } => e.offset_lines(self.first_line - 1),
+            e => e.at_line(self.first_line, self.lines().next().unwrap_or("")),
+        })
+    }
+
+    pub fn parse_lines<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Into<Error>,
+    {
+        map_lines(self.first_line, self.lines())
+    }
+}
+
+impl<'a> Groups<'a> {
+    /// Returns everything after the blank lines that follow the last group,
+    /// blank lines included, as a single group.
+    pub fn remainder(mut self) -> Group<'a> {
+        self.skip_blank_lines();
+        Group {
+            first_line: self.line,
+            text: self.rest.trim_end(),
+        }
+    }
+
+    fn skip_blank_lines(&mut self) {
+        while !self.rest.is_empty() {
+            let (line, rest) = split_first_line(self.rest);
+            if !line.trim().is_empty() {
+                break;
+            }
+            self.rest = rest;
+            self.line += 1;
+        }
+    }
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = Group<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_blank_lines();
+        if self.rest.is_empty() {
+            return None;
+        }
+        let start = self.rest;
+        let first_line = self.line;
+        let mut len = 0;
+        while !self.rest.is_empty() {
+            let (line, rest) = split_first_line(self.rest);
+            if line.trim().is_empty() {
+                break;
+            }
+            len = start.len() - self.rest.len() + line.len();
+            self.rest = rest;
+            self.line += 1;
+        }
+        Some(Group {
+            first_line,
+            text: &start[..len],
+        })
+    }
+}
+
+/// Splits off the first line of `s`, without its line ending.
+fn split_first_line(s: &str) -> (&str, &str) {
+    match s.find('\n') {
+        Some(i) => (s[..i].trim_end_matches('\r'), &s[i + 1..]),
+        None => (s, ""),
+    }
+}
+
+pub fn read_to_string(file_path: &str) -> Result<String> {
+    fs::read_to_string(file_path).map_err(|source| Error::Io {
+        path: file_path.to_string(),
+        source,
+    })
+}
+
+/// The byte offset of `token` in `line`, of which it must be a sub-slice,
+/// as the tokens of parsers are. Panics otherwise rather than report a
+/// wrong column.
+pub fn slice_offset(line: &str, token: &str) -> usize {
+    let outer = line.as_bytes().as_ptr_range();
+    let inner = token.as_bytes().as_ptr_range();
+    assert!(
+        outer.start <= inner.start && inner.end <= outer.end,
+        "{:?} is not a slice of {:?}",
+        token,
+        line
+    );
+    inner.start as usize - outer.start as usize
+}
+
+fn parse_line<T>(line: usize, text: &str) -> Result<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Error>,
+{
+    text.parse::<T>().map_err(|e| e.into().at_line(line, text))
+}
+
+#[cfg(test)]
+mod test {
+    use super::groups;
+    use super::map_lines;
+    use super::parse_groups;
+    use super::sections;
+    use super::slice_offset;
+    use super::Error;
+    use super::Group;
+
+    #[test]
+    fn test_slice_offset() {
+        let line = "move 1 from 2";
+        assert_eq!(slice_offset(line, &line[5..6]), 5);
+        assert_eq!(slice_offset(line, &line[13..]), 13);
+        let copy = line.to_string();
+        assert!(std::panic::catch_unwind(|| slice_offset(line, &copy[5..6])).is_err());
+    }
+
+    #[test]
+    fn test_map_lines_reports_line() {
+        let result = map_lines::<u32>(3, "1\n2\nx\n4".lines());
+        match result {
+            Err(Error::Line { line, text, .. }) => {
+                assert_eq!(line, 5);
+                assert_eq!(text, "x");
+            }
+            _ => panic!("expected line error"),
+        }
+    }
+
+    #[test]
+    fn test_map_lines_display() {
+        let err = map_lines::<u32>(1, "12\nabc".lines())
+            .unwrap_err()
+            .in_file("input.txt");
+        assert_eq!(
+            err.to_string(),
+            "input.txt:2: invalid digit found in string in \"abc\""
+        );
+    }
+
+    #[test]
+    fn test_groups() {
+        let data = "\n1\n2\n\n\n3\r\n\r\n4\n5\n";
+        assert_eq!(
+            groups(data).collect::<Vec<_>>(),
+            vec![
+                Group {
+                    first_line: 2,
+                    text: "1\n2"
+                },
+                Group {
+                    first_line: 6,
+                    text: "3"
+                },
+                Group {
+                    first_line: 8,
+                    text: "4\n5"
+                },
+            ]
+        );
+        assert_eq!(groups("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_parse_groups_reports_line() {
+        let sums = parse_groups::<Sum>("1\n2\n\n3\n").unwrap();
+        assert_eq!(sums.iter().map(|s| s.0).collect::<Vec<_>>(), vec![3, 3]);
+        let err = parse_groups::<Sum>("1\n2\n\n3\nx\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5: invalid digit found in string in \"x\""
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let (header, body) = sections("a\nb\n\nc\n\nd\n").unwrap();
+        assert_eq!(
+            header,
+            Group {
+                first_line: 1,
+                text: "a\nb"
+            }
+        );
+        assert_eq!(
+            body,
+            Group {
+                first_line: 4,
+                text: "c\n\nd"
+            }
+        );
+        assert_eq!(body.parse_lines::<String>().unwrap(), vec!["c", "", "d"]);
+        assert!(sections("").is_err());
+    }
+
+    #[derive(Debug)]
+    struct Sum(u32);
+
+    impl std::str::FromStr for Sum {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Sum(super::parse_lines::<u32>(s)?.iter().sum()))
+        }
+    }
 }
@@ -0,0 +1,155 @@
+use crate::grid::Grid;
+use crate::Error;
+use crate::Result;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// Glyphs are separated by one dark column.
+const GLYPH_STEP: usize = GLYPH_WIDTH + 1;
+
+/// The block letters drawn by the puzzles' screens, `#` for a lit pixel.
+const ALPHABET: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters of a screen `GLYPH_HEIGHT` pixels tall, where `true`
+/// is a lit pixel. The error lists the columns, counted from 1, of every
+/// glyph that is not in the alphabet.
+pub fn recognise(pixels: &Grid<bool>) -> Result<String> {
+    if pixels.height() != GLYPH_HEIGHT {
+        return Err(Error::structure(format!(
+            "expected a screen {} pixels tall, found {}",
+            GLYPH_HEIGHT,
+            pixels.height()
+        )));
+    }
+    let mut text = String::new();
+    let mut unrecognised = Vec::new();
+    for x in (0..pixels.width()).step_by(GLYPH_STEP) {
+        let columns = x..(x + GLYPH_WIDTH).min(pixels.width());
+        let glyph = ALPHABET.iter().find(|(_, rows)| {
+            columns.len() == GLYPH_WIDTH
+                && rows.iter().enumerate().all(|(y, row)| {
+                    row.bytes()
+                        .zip(columns.clone())
+                        .all(|(c, x)| (c == b'#') == pixels[(x, y)])
+                })
+        });
+        match glyph {
+            Some(&(letter, _)) => text.push(letter),
+            None => unrecognised.push(format!("{}-{}", columns.start + 1, columns.end)),
+        }
+    }
+    if !unrecognised.is_empty() {
+        return Err(Error::structure(format!(
+            "unrecognised glyphs at columns {}",
+            unrecognised.join(", ")
+        )));
+    }
+    Ok(text)
+}
+
+/// Reads the letters of a screen drawn as text, with `#` for lit pixels and
+/// `.` or a space for dark ones.
+pub fn recognise_text(screen: &str) -> Result<String> {
+    let pixels = Grid::parse(screen, "#, . or a space", |c| match c {
+        '#' => Some(true),
+        '.' | ' ' => Some(false),
+        _ => None,
+    })?;
+    recognise(&pixels)
+}
+
+/// The letters that `recognise` can read.
+pub fn letters() -> impl Iterator<Item = char> {
+    ALPHABET.iter().map(|&(letter, _)| letter)
+}
+
+/// Draws `text` the way the puzzles' screens do, each glyph followed by a
+/// dark column; the inverse of `recognise`.
+pub fn render(text: &str) -> Result<Grid<bool>> {
+    let glyphs = text
+        .chars()
+        .map(|c| {
+            ALPHABET
+                .iter()
+                .find(|&&(letter, _)| letter == c)
+                .map(|(_, rows)| rows)
+                .ok_or_else(|| Error::structure(format!("no glyph for {:?}", c)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut pixels = Grid::new(GLYPH_STEP * glyphs.len(), GLYPH_HEIGHT, false);
+    for (i, rows) in glyphs.iter().enumerate() {
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.bytes().enumerate() {
+                pixels[(i * GLYPH_STEP + x, y)] = c == b'#';
+            }
+        }
+    }
+    Ok(pixels)
+}
+
+#[cfg(test)]
+mod test {
+    use super::letters;
+    use super::recognise;
+    use super::recognise_text;
+    use super::render;
+
+    const SCREEN: &str = "\
+        ####.#..#.####.####.####.#..#..##..####.\n\
+        #....#..#....#.#.......#.#..#.#..#....#.\n\
+        ###..####...#..###....#..####.#......#..\n\
+        #....#..#..#...#.....#...#..#.#.....#...\n\
+        #....#..#.#....#....#....#..#.#..#.#....\n\
+        ####.#..#.####.#....####.#..#..##..####.\n";
+
+    #[test]
+    fn test_recognise() {
+        assert_eq!(recognise_text(SCREEN).unwrap(), "EHZFZHCZ");
+        assert_eq!(
+            recognise_text(&SCREEN.replace('.', " ")).unwrap(),
+            "EHZFZHCZ"
+        );
+    }
+
+    #[test]
+    fn test_unrecognised() {
+        let mut rows = SCREEN.lines().map(String::from).collect::<Vec<_>>();
+        rows[0].replace_range(1..2, ".");
+        rows[3].replace_range(17..18, "#");
+        assert_eq!(
+            recognise_text(&rows.join("\n")).unwrap_err().to_string(),
+            "unrecognised glyphs at columns 1-4, 16-19"
+        );
+        assert_eq!(
+            recognise_text("#..#\n").unwrap_err().to_string(),
+            "expected a screen 6 pixels tall, found 1"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let text = letters().collect::<String>();
+        assert_eq!(recognise(&render(&text).unwrap()).unwrap(), text);
+        assert_eq!(render("EHZ").unwrap().width(), 15);
+        assert_eq!(render("aB").unwrap_err().to_string(), "no glyph for 'a'");
+    }
+}
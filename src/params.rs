@@ -0,0 +1,87 @@
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Numbers that a puzzle gives beside its input rather than in it, such as
+/// the row that day 15 asks about, written as `name=value,...`. The
+/// example of the puzzle text often uses different ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(Vec<(String, i64)>);
+
+impl Params {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<i64> {
+        self.0
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// Sets `name`, replacing its previous value.
+    pub fn with(mut self, name: &str, value: i64) -> Self {
+        match self.0.iter_mut().find(|(n, _)| n == name) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((name.to_string(), value)),
+        }
+        self
+    }
+
+    /// These parameters with those of `overrides` replacing or adding to them.
+    pub fn overridden_by(&self, overrides: &Params) -> Params {
+        overrides
+            .0
+            .iter()
+            .fold(self.clone(), |params, (name, value)| {
+                params.with(name, *value)
+            })
+    }
+}
+
+impl FromStr for Params {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for entry in s.split(',').filter(|e| !e.is_empty()) {
+            let (name, value) = entry
+                .split_once('=')
+                .ok_or_else(|| Error::unexpected_in(s, entry, "name=value"))?;
+            let value = value
+                .parse()
+                .map_err(|_| Error::unexpected_in(s, value, "a number"))?;
+            params = params.with(name, value);
+        }
+        Ok(params)
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = self
+            .0
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>();
+        write!(f, "{}", entries.join(","))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Params;
+
+    #[test]
+    fn test_params() {
+        let params = "row=10,max=20".parse::<Params>().unwrap();
+        assert_eq!((params.get("row"), params.get("x")), (Some(10), None));
+        let params = params.overridden_by(&"max=4,extra=1".parse().unwrap());
+        assert_eq!(params.to_string(), "row=10,max=4,extra=1");
+        assert_eq!(
+            "row=1,max".parse::<Params>().unwrap_err().to_string(),
+            "column 7: expected name=value, found \"max\""
+        );
+    }
+}
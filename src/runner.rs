@@ -0,0 +1,733 @@
+use crate::frames::Frames;
+use crate::heap;
+use crate::heap::Usage;
+use crate::input::Input;
+use crate::input::Source;
+use crate::params::Params;
+use crate::stream::Reader;
+use crate::Day;
+use crate::Error;
+use crate::Part;
+use crate::Result;
+use std::any::Any;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+/// The outcome of one part of a day.
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<String>,
+    pub elapsed: Duration,
+    /// Heap usage, with the `count-allocations` feature.
+    pub memory: Option<Usage>,
+}
+
+/// The outcome of a day on one input. Parse failures are reported as an
+/// error of `run` instead, since no part can run without a parsed input.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u32,
+    pub input: String,
+    /// See `Input::hash`; `None` for a streamed input that was not read to
+    /// its end, see `run_reader`.
+    pub input_hash: Option<u64>,
+    pub parse_elapsed: Duration,
+    /// Heap usage of parsing, with the `count-allocations` feature.
+    pub parse_memory: Option<Usage>,
+    pub parts: Vec<PartRun>,
+}
+
+/// How one day of `run_parallel` ended.
+#[derive(Debug)]
+pub enum DayOutcome {
+    /// The input was parsed; the parts may still have failed.
+    Ran(DayRun),
+    /// The input could not be read or parsed.
+    Failed(Error),
+    /// Reading or parsing the input panicked, see `Error::Panicked`. Panics
+    /// of the parts are reported as their answers instead.
+    Panicked(Error),
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u32,
+    pub outcome: DayOutcome,
+    /// Wall time including reading the input.
+    pub elapsed: Duration,
+}
+
+/// Minimum, median and maximum of repeated measurements of one stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+#[derive(Debug)]
+pub struct Bench {
+    pub day: u32,
+    pub input: String,
+    pub stages: Vec<(Stage, Stats)>,
+}
+
+pub fn run(day: &Day, input: &Input, parts: &[Part]) -> Result<DayRun> {
+    let start = Instant::now();
+    let (parsed, parse_memory) = heap::measure(|| day.parse(&input.data, &input.params));
+    let parsed = parsed.map_err(|e| e.in_file(&input.name))?;
+    let parse_elapsed = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, memory) =
+                heap::measure(|| catch_panic(|| day.solve(parsed.as_ref(), part)));
+            PartRun {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+                memory,
+            }
+        })
+        .collect();
+    Ok(DayRun {
+        day: day.number,
+        input: input.name.clone(),
+        input_hash: Some(input.hash()),
+        parse_elapsed,
+        parse_memory,
+        parts,
+    })
+}
+
+/// Runs the day on the input of `source`, with `params` overriding those
+/// of the input. See `run_reader`.
+pub fn run_source(
+    day: &Day,
+    source: &Source,
+    params: &Params,
+    parts: &[Part],
+    hash: bool,
+) -> Result<DayRun> {
+    let reader = source.open(day.number)?;
+    let params = source.params(day.number).overridden_by(params);
+    run_reader(day, reader, params, parts, hash)
+}
+
+/// Runs the day on the input of `reader`. Large inputs, see
+/// `Reader::is_large`, are streamed to days that can solve them in one
+/// pass, which is measured as the parse stage; the parts then take no time
+/// or memory of their own. A streamed input is read to its end for its hash
+/// only if `hash`.
+pub fn run_reader(
+    day: &Day,
+    mut reader: Reader,
+    params: Params,
+    parts: &[Part],
+    hash: bool,
+) -> Result<DayRun> {
+    if !reader.is_large() {
+        return run(day, &Input::read(reader, params)?, parts);
+    }
+    let start = Instant::now();
+    let (answers, parse_memory) = heap::measure(|| day.solve_streamed(&mut reader, parts));
+    let parse_elapsed = start.elapsed();
+    let answers = match answers {
+        Some(answers) => answers.map_err(|e| e.in_file(reader.name()))?,
+        None => return run(day, &Input::read(reader, params)?, parts),
+    };
+    let input_hash = if hash {
+        reader.skip_to_end()?;
+        Some(reader.hash())
+    } else {
+        None
+    };
+    Ok(DayRun {
+        day: day.number,
+        input: reader.name().to_string(),
+        input_hash,
+        parse_elapsed,
+        parse_memory,
+        parts: parts
+            .iter()
+            .zip(answers)
+            .map(|(&part, answer)| PartRun {
+                part,
+                answer: Ok(answer),
+                elapsed: Duration::ZERO,
+                memory: None,
+            })
+            .collect(),
+    })
+}
+
+/// Runs `days` on a pool of `threads` threads, each day reading its input
+/// from `source` with `params` overriding those of the input. A panicking
+/// day is reported without stopping the others. The reports are in the
+/// order of `days`. See `install_panic_hook` to keep the panics quiet.
+pub fn run_parallel(
+    days: &[&Day],
+    source: &Source,
+    params: &Params,
+    parts: &[Part],
+    threads: usize,
+) -> Vec<DayReport> {
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(Vec::with_capacity(days.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            let worker = thread::Builder::new().name(WORKER.to_string());
+            let spawned = worker.spawn_scoped(scope, || {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let start = Instant::now();
+                    let outcome =
+                        match catch_panic(|| run_source(day, source, params, parts, false)) {
+                            Ok(run) => DayOutcome::Ran(run),
+                            Err(e @ Error::Panicked { .. }) => DayOutcome::Panicked(e),
+                            Err(e) => DayOutcome::Failed(e),
+                        };
+                    let report = DayReport {
+                        day: day.number,
+                        outcome,
+                        elapsed: start.elapsed(),
+                    };
+                    reports.lock().unwrap().push(report);
+                }
+            });
+            spawned.expect("cannot spawn a worker thread");
+        }
+    });
+    let mut reports = reports.into_inner().unwrap();
+    reports.sort_by_key(|r| days.iter().position(|d| d.number == r.day));
+    reports
+}
+
+/// The name of the threads of `run_parallel`.
+const WORKER: &str = "aoc-worker";
+
+thread_local! {
+    /// Where the last panic of this thread happened, see `install_panic_hook`.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// How many calls of `catch_panic` this thread is inside.
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Marks the thread as inside `catch_panic` while it lives, unwinding
+/// included.
+struct Catching;
+
+impl Catching {
+    fn enter() -> Self {
+        CATCHING.with(|depth| depth.set(depth.get() + 1));
+        Catching
+    }
+}
+
+impl Drop for Catching {
+    fn drop(&mut self) {
+        CATCHING.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Installs a panic hook that records where each panic happened, so that
+/// the errors of caught panics name it. Panics caught by the runner are
+/// left to its reports; others still go to the previous hook.
+pub fn install_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let location = info.location().map(|l| l.to_string());
+        PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+        if CATCHING.with(Cell::get) == 0 {
+            previous(info);
+        }
+    }));
+}
+
+/// Runs `f`, turning a panic into an `Error::Panicked`.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    PANIC_LOCATION.with(|cell| cell.borrow_mut().take());
+    let catching = Catching::enter();
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    drop(catching);
+    result.unwrap_or_else(|payload| {
+        Err(Error::Panicked {
+            message: panic_message(payload.as_ref()),
+            location: PANIC_LOCATION.with(|cell| cell.borrow_mut().take()),
+        })
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Lays out `rows` under `header` in columns two spaces apart, padding
+/// each cell to the widest in its column.
+pub fn format_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let header = header.iter().map(|h| h.to_string()).collect::<Vec<_>>();
+    let rows = std::iter::once(&header).chain(rows).collect::<Vec<_>>();
+    let columns = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let widths = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|r| r.get(i))
+                .map(|c| c.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    let mut table = String::new();
+    for row in rows {
+        let mut line = String::new();
+        for (cell, width) in row.iter().zip(&widths) {
+            write!(line, "{:<width$}  ", cell, width = width).unwrap();
+        }
+        table += line.trim_end();
+        table.push('\n');
+    }
+    table
+}
+
+/// Replays one part of the day, reporting its steps to `frames`.
+pub fn visualise(day: &Day, input: &Input, part: Part, frames: &mut dyn Frames) -> Result<()> {
+    let parsed = day
+        .parse(&input.data, &input.params)
+        .map_err(|e| e.in_file(&input.name))?;
+    day.visualise(parsed.as_ref(), part, frames)
+}
+
+/// Runs the day `runs` times and summarises the time of each stage. Fails
+/// on the first error, since timings of a failing solver are meaningless.
+pub fn bench(day: &Day, input: &Input, parts: &[Part], runs: usize) -> Result<Bench> {
+    let mut samples = vec![Vec::with_capacity(runs); parts.len() + 1];
+    for _ in 0..runs {
+        let result = run(day, input, parts)?;
+        samples[0].push(result.parse_elapsed);
+        for (i, part) in result.parts.into_iter().enumerate() {
+            part.answer?;
+            samples[i + 1].push(part.elapsed);
+        }
+    }
+    let stages = std::iter::once(Stage::Parse)
+        .chain(parts.iter().map(|&p| Stage::Part(p)))
+        .zip(samples)
+        .map(|(stage, samples)| (stage, Stats::from_samples(samples)))
+        .collect();
+    Ok(Bench {
+        day: day.number,
+        input: input.name.clone(),
+        stages,
+    })
+}
+
+impl Stats {
+    /// Summarises a non-empty list of measurements.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Stats {
+            runs: samples.len(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl std::fmt::Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl DayRun {
+    pub fn to_bench(&self) -> Bench {
+        let once = |elapsed| Stats::from_samples(vec![elapsed]);
+        let stages = std::iter::once((Stage::Parse, once(self.parse_elapsed)))
+            .chain(
+                self.parts
+                    .iter()
+                    .map(|p| (Stage::Part(p.part), once(p.elapsed))),
+            )
+            .collect();
+        Bench {
+            day: self.day,
+            input: self.input.clone(),
+            stages,
+        }
+    }
+
+    /// One line per part as described at `json_line`.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        for part in &self.parts {
+            let (answer, error) = match &part.answer {
+                Ok(answer) => (json_string(answer), "null".to_string()),
+                Err(e) => ("null".to_string(), json_string(&e.to_string())),
+            };
+            json += &json_line(&[
+                ("day", self.day.to_string()),
+                ("part", part.part.to_string()),
+                ("input", json_string(&self.input)),
+                (
+                    "input_hash",
+                    self.input_hash.map_or("null".to_string(), |hash| {
+                        json_string(&format!("{:016x}", hash))
+                    }),
+                ),
+                ("answer", answer),
+                ("error", error),
+                ("parse_ns", self.parse_elapsed.as_nanos().to_string()),
+                (
+                    "parse_peak_bytes",
+                    json_memory(self.parse_memory, |m| m.peak_bytes),
+                ),
+                (
+                    "parse_allocated_bytes",
+                    json_memory(self.parse_memory, |m| m.allocated_bytes),
+                ),
+                (
+                    "parse_allocations",
+                    json_memory(self.parse_memory, |m| m.allocations),
+                ),
+                ("elapsed_ns", part.elapsed.as_nanos().to_string()),
+                ("peak_bytes", json_memory(part.memory, |m| m.peak_bytes)),
+                (
+                    "allocated_bytes",
+                    json_memory(part.memory, |m| m.allocated_bytes),
+                ),
+                ("allocations", json_memory(part.memory, |m| m.allocations)),
+            ]);
+        }
+        json
+    }
+}
+
+pub const CSV_HEADER: &str = "day,input,stage,runs,min_ns,median_ns,max_ns";
+
+impl Bench {
+    /// Formats the stages as CSV rows matching `CSV_HEADER`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for (stage, stats) in &self.stages {
+            writeln!(
+                csv,
+                "{},{},{},{},{},{},{}",
+                self.day,
+                csv_field(&self.input),
+                stage,
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            )
+            .unwrap();
+        }
+        csv
+    }
+}
+
+/// The JSON lines of parts that could not run because their input failed
+/// to load or parse; `input` is `None` if it could not be read at all.
+pub fn failure_json(day: u32, parts: &[Part], input: Option<&Input>, error: &Error) -> String {
+    let mut json = String::new();
+    for part in parts {
+        json += &json_line(&[
+            ("day", day.to_string()),
+            ("part", part.to_string()),
+            (
+                "input",
+                input.map_or("null".to_string(), |i| json_string(&i.name)),
+            ),
+            (
+                "input_hash",
+                input.map_or("null".to_string(), |i| {
+                    json_string(&format!("{:016x}", i.hash()))
+                }),
+            ),
+            ("answer", "null".to_string()),
+            ("error", json_string(&error.to_string())),
+            ("parse_ns", "null".to_string()),
+            ("parse_peak_bytes", "null".to_string()),
+            ("parse_allocated_bytes", "null".to_string()),
+            ("parse_allocations", "null".to_string()),
+            ("elapsed_ns", "null".to_string()),
+            ("peak_bytes", "null".to_string()),
+            ("allocated_bytes", "null".to_string()),
+            ("allocations", "null".to_string()),
+        ]);
+    }
+    json
+}
+
+/// A JSON object on a single line, with the keys `day`, `part`, `input`,
+/// `input_hash` (hexadecimal, see `Input::hash`), `answer`, `error`,
+/// `parse_ns`, `parse_peak_bytes`, `parse_allocated_bytes`,
+/// `parse_allocations`, `elapsed_ns`, and `peak_bytes`, `allocated_bytes`
+/// and `allocations` (see `heap::Usage`), each value already encoded.
+fn json_line(fields: &[(&str, String)]) -> String {
+    let fields = fields
+        .iter()
+        .map(|(key, value)| format!("\"{}\":{}", key, value))
+        .collect::<Vec<_>>();
+    format!("{{{}}}\n", fields.join(","))
+}
+
+fn json_memory(memory: Option<Usage>, field: impl Fn(&Usage) -> usize) -> String {
+    memory.map_or("null".to_string(), |m| field(&m).to_string())
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Quotes a CSV field if it holds a separator, a quote or a line break,
+/// doubling the quotes inside.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::catch_panic;
+    use super::failure_json;
+    use super::format_table;
+    use super::install_panic_hook;
+    use super::run_parallel;
+    use super::Bench;
+    use super::DayOutcome;
+    use super::DayRun;
+    use super::PartRun;
+    use super::Stage;
+    use super::Stats;
+    use super::CATCHING;
+    use crate::heap::Usage;
+    use crate::input::Source;
+    use crate::params::Params;
+    use crate::Day;
+    use crate::Error;
+    use crate::Part;
+    use crate::Solution;
+    use std::time::Duration;
+
+    /// Sums the numbers for part 1 and panics on an empty input in part 2.
+    struct Fragile;
+
+    impl Solution for Fragile {
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(data: &str) -> crate::Result<Self::Input> {
+            crate::parse_lines(data)
+        }
+
+        fn part1(input: &Self::Input) -> crate::Result<u32> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> crate::Result<u32> {
+            Ok(input[0])
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(9), ms(3), ms(4)]);
+        assert_eq!(
+            stats,
+            Stats {
+                runs: 5,
+                min: ms(1),
+                median: ms(4),
+                max: ms(9),
+            }
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        let ms = Duration::from_millis;
+        let bench = Bench {
+            day: 6,
+            input: "data/\"day06\", copy.txt".to_string(),
+            stages: vec![(Stage::Parse, Stats::from_samples(vec![ms(1)]))],
+        };
+        assert_eq!(
+            bench.to_csv(),
+            "6,\"data/\"\"day06\"\", copy.txt\",parse,1,1000000,1000000,1000000\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let run = DayRun {
+            day: 10,
+            input: "data/day10.txt".to_string(),
+            input_hash: Some(0xabc),
+            parse_elapsed: Duration::from_nanos(5),
+            parse_memory: Some(Usage {
+                peak_bytes: 30,
+                allocated_bytes: 40,
+                allocations: 3,
+            }),
+            parts: vec![
+                PartRun {
+                    part: Part::One,
+                    answer: Ok("a \"b\"\n".to_string()),
+                    elapsed: Duration::from_nanos(7),
+                    memory: Some(Usage {
+                        peak_bytes: 10,
+                        allocated_bytes: 20,
+                        allocations: 2,
+                    }),
+                },
+                PartRun {
+                    part: Part::Two,
+                    answer: Err(Error::structure("no\tanswer")),
+                    elapsed: Duration::from_nanos(9),
+                    memory: None,
+                },
+            ],
+        };
+        assert_eq!(
+            run.to_json(),
+            concat!(
+                r#"{"day":10,"part":1,"input":"data/day10.txt","input_hash":"0000000000000abc","#,
+                r#""answer":"a \"b\"\n","error":null,"parse_ns":5,"parse_peak_bytes":30,"#,
+                r#""parse_allocated_bytes":40,"parse_allocations":3,"elapsed_ns":7,"#,
+                r#""peak_bytes":10,"allocated_bytes":20,"allocations":2}"#,
+                "\n",
+                r#"{"day":10,"part":2,"input":"data/day10.txt","input_hash":"0000000000000abc","#,
+                r#""answer":null,"error":"no\u0009answer","parse_ns":5,"parse_peak_bytes":30,"#,
+                r#""parse_allocated_bytes":40,"parse_allocations":3,"elapsed_ns":9,"#,
+                r#""peak_bytes":null,"allocated_bytes":null,"allocations":null}"#,
+                "\n",
+            )
+        );
+        assert_eq!(
+            failure_json(3, &[Part::Two], None, &Error::structure("x")),
+            concat!(
+                r#"{"day":3,"part":2,"input":null,"input_hash":null,"answer":null,"error":"x","#,
+                r#""parse_ns":null,"parse_peak_bytes":null,"parse_allocated_bytes":null,"#,
+                r#""parse_allocations":null,"elapsed_ns":null,"peak_bytes":null,"#,
+                r#""allocated_bytes":null,"allocations":null}"#,
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        install_panic_hook();
+        let days = [Day::new::<Fragile>(1), Day::new::<Fragile>(2)];
+        let days = days.iter().collect::<Vec<_>>();
+        let reports = run_parallel(
+            &days,
+            &Source::Text("".to_string()),
+            &Params::default(),
+            &Part::ALL,
+            2,
+        );
+        assert_eq!(
+            reports.iter().map(|r| r.day).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        // Part 2 panics without stopping part 1.
+        for report in reports {
+            let run = match report.outcome {
+                DayOutcome::Ran(run) => run,
+                outcome => panic!("unexpected outcome {:?}", outcome),
+            };
+            assert_eq!(run.parts[0].answer.as_ref().unwrap(), "0");
+            match &run.parts[1].answer {
+                Err(Error::Panicked {
+                    message,
+                    location: Some(location),
+                }) => {
+                    assert!(message.contains("index out of bounds"));
+                    assert!(location.starts_with("src/runner.rs:"));
+                }
+                answer => panic!("unexpected answer {:?}", answer),
+            }
+        }
+
+        let reports = run_parallel(
+            &days[..1],
+            &Source::Text("1\n2\n".to_string()),
+            &Params::default(),
+            &Part::ALL,
+            4,
+        );
+        match &reports[0].outcome {
+            DayOutcome::Ran(run) => assert_eq!(run.parts[0].answer.as_ref().unwrap(), "3"),
+            outcome => panic!("unexpected outcome {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        install_panic_hook();
+        let result = catch_panic(|| {
+            assert_eq!(catch_panic(|| Ok(CATCHING.with(|c| c.get()))).unwrap(), 2);
+            let empty: Vec<u32> = Vec::new();
+            Ok(empty[0])
+        });
+        assert!(matches!(result, Err(Error::Panicked { .. })));
+        assert_eq!(CATCHING.with(|c| c.get()), 0);
+    }
+
+    #[test]
+    fn test_format_table() {
+        let rows = vec![
+            vec!["1".to_string(), "68923".to_string(), "ok".to_string()],
+            vec!["15".to_string(), "-".to_string(), "error".to_string()],
+        ];
+        assert_eq!(
+            format_table(&["day", "part 1", "status"], &rows),
+            "day  part 1  status\n1    68923   ok\n15   -       error\n"
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use crate::Error;
+use crate::Result;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+pub const DAYS_DIR: &str = "src/days";
+
+/// The solver skeleton, with `NN` standing for the zero-padded day number.
+const TEMPLATE: &str = r#"use crate::Error;
+use crate::Solution;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct Item {}
+
+impl FromStr for Item {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Err(Error::unexpected_in(s, s, "an item"))
+    }
+}
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Input = Vec<Item>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> crate::Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part1(_items: &Self::Input) -> crate::Result<usize> {
+        Err(Error::structure("part 1 is not solved yet"))
+    }
+
+    fn part2(_items: &Self::Input) -> crate::Result<usize> {
+        Err(Error::structure("part 2 is not solved yet"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DayNN;
+    use crate::Solution;
+
+    #[test]
+    #[ignore = "fill in data/dayNN-test.txt and its answers"]
+    fn test_example() {
+        let data = crate::read_to_string("data/dayNN-test.txt").unwrap();
+        let input = DayNN::parse(&data).unwrap();
+        assert_eq!(DayNN::part1(&input).unwrap(), 0);
+        assert_eq!(DayNN::part2(&input).unwrap(), 0);
+    }
+}
+"#;
+
+/// Creates the solver of `day` under `root`, registers it in the days
+/// module and creates empty input files for it. Refuses to touch a day that
+/// already has a solver. Returns the files created.
+pub fn create(root: &Path, day: u32) -> Result<Vec<PathBuf>> {
+    let days_dir = root.join(DAYS_DIR);
+    let source = days_dir.join(format!("day{:02}.rs", day));
+    let mod_path = days_dir.join("mod.rs");
+    let mod_name = mod_path.display().to_string();
+    let registry = crate::read_to_string(&mod_name)?;
+    if source.exists() {
+        return Err(Error::structure(format!(
+            "{} already exists",
+            source.display()
+        )));
+    }
+    let registry = register(&registry, day).map_err(|e| e.in_file(&mod_name))?;
+
+    write(&source, &TEMPLATE.replace("NN", &format!("{:02}", day)))?;
+    write(&mod_path, &registry)?;
+    let mut created = vec![source];
+    let data_dir = root.join(crate::input::DATA_DIR);
+    for name in [
+        format!("day{:02}.txt", day),
+        format!("day{:02}-test.txt", day),
+    ] {
+        let path = data_dir.join(name);
+        if !path.exists() {
+            write(&path, "")?;
+            created.push(path);
+        }
+    }
+    Ok(created)
+}
+
+/// Adds `day` to the module declarations and to `ALL` of the days module,
+/// keeping both in order.
+fn register(registry: &str, day: u32) -> Result<String> {
+    let declaration = format!("pub mod day{:02};", day);
+    let entry = format!("    Day::new::<day{:02}::Day{:02}>({}),", day, day, day);
+    let mut lines = registry.lines().map(str::to_string).collect::<Vec<_>>();
+    if lines.contains(&declaration) {
+        return Err(Error::structure(format!(
+            "day {} is already registered",
+            day
+        )));
+    }
+    insert_sorted(&mut lines, declaration, |line| {
+        line.starts_with("pub mod day")
+    })?;
+    insert_sorted(&mut lines, entry, |line| {
+        line.trim_start().starts_with("Day::new::<")
+    })?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` into the contiguous block of lines matching `in_block`,
+/// before the first one that sorts after it.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    line: String,
+    in_block: impl Fn(&str) -> bool,
+) -> Result<()> {
+    let start = lines
+        .iter()
+        .position(|l| in_block(l))
+        .ok_or_else(|| Error::structure(format!("no place found for {:?}", line.trim())))?;
+    let end = start + lines[start..].iter().take_while(|l| in_block(l)).count();
+    let index = (start..end).find(|&i| lines[i] > line).unwrap_or(end);
+    lines.insert(index, line);
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|source| Error::Io {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::create;
+    use super::register;
+    use crate::site::mock;
+    use std::fs;
+
+    const REGISTRY: &str = "use crate::Day;
+
+pub mod day01;
+pub mod day13;
+
+pub const ALL: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day13::Day13>(13),
+];
+";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register(REGISTRY, 11).unwrap(),
+            "use crate::Day;
+
+pub mod day01;
+pub mod day11;
+pub mod day13;
+
+pub const ALL: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day13::Day13>(13),
+];
+"
+        );
+        assert!(register(REGISTRY, 13).is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let root = mock::temp_dir("scaffold");
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+        fs::write(root.join("data/day11.txt"), "42\n").unwrap();
+
+        let created = create(&root, 11).unwrap();
+        assert_eq!(
+            created,
+            vec![
+                root.join("src/days/day11.rs"),
+                root.join("data/day11-test.txt")
+            ]
+        );
+        let code = fs::read_to_string(root.join("src/days/day11.rs")).unwrap();
+        assert!(code.contains("pub struct Day11;"));
+        assert!(code.contains("\"data/day11-test.txt\""));
+        assert_eq!(
+            fs::read_to_string(root.join("data/day11.txt")).unwrap(),
+            "42\n"
+        );
+        assert!(fs::read_to_string(root.join("src/days/mod.rs"))
+            .unwrap()
+            .contains("pub mod day11;"));
+
+        fs::write(root.join("src/days/day11.rs"), "// solved").unwrap();
+        assert!(create(&root, 11).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/day11.rs")).unwrap(),
+            "// solved"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
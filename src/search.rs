@@ -0,0 +1,253 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// A shortest path: its total cost and every state from a start to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S> {
+    pub cost: u64,
+    pub path: Vec<S>,
+}
+
+/// The states seen so far, numbered in the order they were reached, with
+/// the state each one was reached from.
+struct Visited<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new() -> Self {
+        Visited {
+            states: Vec::new(),
+            index: HashMap::new(),
+            parent: Vec::new(),
+        }
+    }
+
+    /// Numbers `state` unless it was seen before; returns its number and
+    /// whether it is new.
+    fn add(&mut self, state: S, parent: Option<usize>) -> (usize, bool) {
+        if let Some(&i) = self.index.get(&state) {
+            return (i, false);
+        }
+        let i = self.states.len();
+        self.index.insert(state.clone(), i);
+        self.states.push(state);
+        self.parent.push(parent);
+        (i, true)
+    }
+
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.states[i].clone()];
+        while let Some(parent) = self.parent[i] {
+            path.push(self.states[parent].clone());
+            i = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search from all of `starts` at once, where every step
+/// costs 1. Returns a shortest path to the nearest state satisfying `goal`.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let (i, true) = visited.add(start, None) {
+            queue.push_back(i);
+        }
+    }
+    while let Some(i) = queue.pop_front() {
+        if goal(&visited.states[i]) {
+            let path = visited.path(i);
+            return Some(Found {
+                cost: path.len() as u64 - 1,
+                path,
+            });
+        }
+        let state = visited.states[i].clone();
+        for next in successors(&state) {
+            if let (j, true) = visited.add(next, Some(i)) {
+                queue.push_back(j);
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps from the nearest of `starts` to every reachable state.
+pub fn distances<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, u64>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state];
+        for next in successors(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's search from all of `starts`, with `successors` returning
+/// each next state together with the cost of the step to it.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a
+/// goal, or the path found may not be the shortest.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut visited = Visited::new();
+    let mut best = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if let (i, true) = visited.add(start, None) {
+            best.push(0);
+            heap.push(Reverse((heuristic(&visited.states[i]), 0, i)));
+        }
+    }
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > best[i] {
+            continue;
+        }
+        if goal(&visited.states[i]) {
+            return Some(Found {
+                cost,
+                path: visited.path(i),
+            });
+        }
+        let state = visited.states[i].clone();
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let (j, new) = visited.add(next, Some(i));
+            if new {
+                best.push(next_cost);
+            } else if next_cost < best[j] {
+                best[j] = next_cost;
+                visited.parent[j] = Some(i);
+            } else {
+                continue;
+            }
+            heap.push(Reverse((
+                next_cost + heuristic(&visited.states[j]),
+                next_cost,
+                j,
+            )));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::astar;
+    use super::bfs;
+    use super::dijkstra;
+    use super::distances;
+    use crate::grid::Grid;
+
+    /// The hill climbing example: one step up at most, any step down.
+    const HILL: &str = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n";
+
+    fn height(c: char) -> u8 {
+        match c {
+            'S' => b'a',
+            'E' => b'z',
+            c => c as u8,
+        }
+    }
+
+    #[test]
+    fn test_bfs_on_grid() {
+        let grid = Grid::parse(HILL, "a letter", |c| {
+            Some(c).filter(char::is_ascii_alphabetic)
+        })
+        .unwrap();
+        let find = |c| grid.positions().find(|&p| grid[p] == c).unwrap();
+        let (start, end) = (find('S'), find('E'));
+        let climb = |a: &char, b: &char| height(*b) <= height(*a) + 1;
+
+        let found = bfs([start], |&p| grid.moves(p, climb), |&p| p == end).unwrap();
+        assert_eq!(found.cost, 31);
+        assert_eq!(found.path.len(), 32);
+        assert_eq!((found.path[0], found.path[31]), (start, end));
+
+        let lowest = grid.positions().filter(|&p| height(grid[p]) == b'a');
+        assert_eq!(
+            bfs(lowest, |&p| grid.moves(p, climb), |&p| p == end)
+                .unwrap()
+                .cost,
+            29
+        );
+        assert_eq!(bfs([start], |&p| grid.moves(p, climb), |_| false), None);
+
+        let reachable = distances([start], |&p| grid.moves(p, climb).collect::<Vec<_>>());
+        assert_eq!(reachable[&end], 31);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Moving down costs 1, moving right costs one more than the row.
+        let successors = |&(x, y): &(u64, u64)| [((x + 1, y), y + 1), ((x, y + 1), 1)];
+        let found = dijkstra([(0, 0)], successors, |&p| p == (2, 3)).unwrap();
+        assert_eq!(found.cost, 5);
+        let found = astar(
+            [(0, 0)],
+            successors,
+            |&(x, y)| 2 - x.min(2) + 3 - y.min(3),
+            |&p| p == (2, 3),
+        )
+        .unwrap();
+        assert_eq!(found.cost, 5);
+        assert_eq!(
+            found.path,
+            vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)]
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use crate::Error;
+use crate::Result;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const YEAR: u32 = 2022;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = "github.com/MikhailFominykh/adventofcode aoc-2022 runner";
+
+/// Environment variables that override the defaults.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The session cookie is looked up in this file when `AOC_SESSION` is not
+/// set, first in the current directory and then in the home directory.
+pub const SESSION_FILE: &str = ".aoc-session";
+
+/// A client for the puzzle website, authenticated with a session cookie.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Creates a client from `AOC_BASE_URL` and the session token found in
+    /// `AOC_SESSION` or the session file.
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &find_session()?))
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    pub fn get(&self, path: &str) -> Result<String> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(&url, response)
+    }
+
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(fields);
+        read_response(&url, response)
+    }
+
+    pub fn fetch_input(&self, day: u32) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", YEAR, day))
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: std::result::Result<ureq::Response, ureq::Error>,
+) -> Result<String> {
+    let http_error = |message: String| Error::Http {
+        url: url.to_string(),
+        message,
+    };
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| http_error(e.to_string())),
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(http_error(format!("status {}: {}", code, body.trim())))
+        }
+        Err(ureq::Error::Transport(transport)) => {
+            // The transport error's own message repeats the URL.
+            let message = match std::error::Error::source(&transport) {
+                Some(source) => format!("{}: {}", transport.kind(), source),
+                None => transport.kind().to_string(),
+            };
+            Err(http_error(message))
+        }
+    }
+}
+
+fn find_session() -> Result<String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Ok(session);
+    }
+    let mut candidates = vec![PathBuf::from(SESSION_FILE)];
+    if let Some(home) = env::var_os("HOME") {
+        candidates.push(Path::new(&home).join(SESSION_FILE));
+    }
+    candidates
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| {
+            Error::structure(format!(
+                "no session token: set {} or put it into {}",
+                SESSION_VAR, SESSION_FILE
+            ))
+        })
+}
+
+/// Makes sure the input of `day` is in `dir`, downloading it only if the
+/// file is missing or empty. Returns the path and whether it was downloaded.
+pub fn fetch_input_to(client: &Client, day: u32, dir: &Path) -> Result<(PathBuf, bool)> {
+    let path = dir.join(format!("day{:02}.txt", day));
+    if fs::metadata(&path).map(|m| m.len() > 0).unwrap_or(false) {
+        return Ok((path, false));
+    }
+    let data = client.fetch_input(day)?;
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&path, data))
+        .map_err(|source| Error::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+    Ok((path, true))
+}
+
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::BufRead;
+    use std::io::BufReader;
+    use std::io::Read;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::thread;
+    use std::thread::JoinHandle;
+
+    /// Serves one canned `(status, body)` response per connection and
+    /// returns the raw requests it received.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(v) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = v.trim().parse().unwrap();
+                    }
+                    request += &line;
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request += &String::from_utf8(content).unwrap();
+                requests.push(request);
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    /// A fresh empty directory under the system temporary directory.
+    pub fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::fetch_input_to;
+    use super::mock;
+    use super::Client;
+    use super::USER_AGENT;
+    use std::fs;
+
+    #[test]
+    fn test_fetch_input_is_cached() {
+        let (base_url, server) = mock::serve(vec![(200, "1\n2\n")]);
+        let client = Client::new(&base_url, "abc123");
+        let dir = mock::temp_dir("fetch");
+
+        let (path, downloaded) = fetch_input_to(&client, 7, &dir).unwrap();
+        assert!(downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n");
+        let (_, downloaded) = fetch_input_to(&client, 7, &dir).unwrap();
+        assert!(!downloaded);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = requests[0].to_ascii_lowercase();
+        assert!(request.starts_with("get /2022/day/7/input "));
+        assert!(request.contains("cookie: session=abc123\r\n"));
+        assert!(request.contains(&format!(
+            "user-agent: {}\r\n",
+            USER_AGENT.to_ascii_lowercase()
+        )));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let (base_url, server) = mock::serve(vec![(404, "Not Found")]);
+        let client = Client::new(&base_url, "abc123");
+        let dir = mock::temp_dir("fetch-error");
+
+        let err = fetch_input_to(&client, 25, &dir).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("{}/2022/day/25/input: status 404: Not Found", base_url)
+        );
+        assert!(!dir.join("day25.txt").exists());
+        server.join().unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,278 @@
+use crate::frames::Frames;
+use crate::generate::Rng;
+use crate::generate::Size;
+use crate::input::Input;
+use crate::input::Source;
+use crate::params::Params;
+use crate::stream::Reader;
+use crate::validate;
+use crate::Error;
+use crate::Result;
+use std::any::Any;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The answers of `Solution::solve_streamed` in the order of the parts
+/// asked for, or `None` if the day cannot stream.
+pub type StreamedAnswers = Option<Result<Vec<String>>>;
+
+/// A puzzle solver: the input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(data: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Parses an input together with the parameters given beside it. Only
+    /// puzzles that have some override this; `parse` then uses those of
+    /// the real input.
+    fn parse_with(data: &str, _params: &Params) -> Result<Self::Input> {
+        Self::parse(data)
+    }
+
+    /// The parameters of the example in the puzzle text.
+    fn example_params() -> Params {
+        Params::default()
+    }
+
+    /// Solves `parts` in one pass over an input too large to hold in
+    /// memory, giving the answers `parse` and the parts would give on the
+    /// whole input. Days that can only solve a whole input return `None`
+    /// without reading anything, and the input is then read whole.
+    fn solve_streamed(_reader: &mut Reader, _parts: &[Part]) -> StreamedAnswers {
+        None
+    }
+
+    /// Replays a part step by step, reporting each state to `frames`.
+    /// Only days that simulate something on a map override this.
+    fn visualise(_input: &Self::Input, part: Part, _frames: &mut dyn Frames) -> Result<()> {
+        Err(no_visualisation(part))
+    }
+
+    /// Checks the shape of an input without solving it and returns every
+    /// violation found, located at its line. Days override this with rules
+    /// that keep going past the first problem; by default only the first
+    /// error of `parse` is reported.
+    fn validate(data: &str) -> Vec<Error> {
+        Self::parse(data).err().into_iter().collect()
+    }
+
+    /// Writes a random input that is valid for the puzzle. The same `rng`
+    /// state always gives the same input.
+    fn generate(_rng: &mut Rng, _size: Size) -> Result<String> {
+        Err(Error::structure("no input generator"))
+    }
+
+    /// The parameters to solve an input of `generate` with.
+    fn generated_params(_size: Size) -> Params {
+        Params::default()
+    }
+}
+
+/// The error of `Solution::visualise` for a part that cannot be replayed,
+/// for days that only replay some of their parts.
+pub fn no_visualisation(part: Part) -> Error {
+    Error::structure(format!("part {} has no visualisation", part))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u32) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.parse()
+            .ok()
+            .and_then(Part::from_number)
+            .ok_or_else(|| Error::unexpected(1, s, "1 or 2"))
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A registered day with its solver's types erased, so that the runner
+/// can treat every day the same way.
+pub struct Day {
+    pub number: u32,
+    parse: fn(&str, &Params) -> Result<Box<dyn Any>>,
+    example_params: fn() -> Params,
+    solve: fn(&dyn Any, Part) -> Result<String>,
+    solve_streamed: fn(&mut Reader, &[Part]) -> StreamedAnswers,
+    visualise: fn(&dyn Any, Part, &mut dyn Frames) -> Result<()>,
+    validate: fn(&str) -> Vec<Error>,
+    generate: fn(&mut Rng, Size) -> Result<String>,
+    generated_params: fn(Size) -> Params,
+}
+
+impl Day {
+    pub const fn new<S>(number: u32) -> Self
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Day {
+            number,
+            parse: parse_erased::<S>,
+            example_params: S::example_params,
+            solve: solve_erased::<S>,
+            solve_streamed: S::solve_streamed,
+            visualise: visualise_erased::<S>,
+            validate: S::validate,
+            generate: S::generate,
+            generated_params: S::generated_params,
+        }
+    }
+
+    pub fn parse(&self, data: &str, params: &Params) -> Result<Box<dyn Any>> {
+        (self.parse)(data, params)
+    }
+
+    pub fn example_params(&self) -> Params {
+        (self.example_params)()
+    }
+
+    /// Solves one part for an input returned by `parse` of the same day.
+    pub fn solve(&self, input: &dyn Any, part: Part) -> Result<String> {
+        (self.solve)(input, part)
+    }
+
+    /// Solves `parts` as `reader` goes, if the day can; see
+    /// `Solution::solve_streamed`.
+    pub fn solve_streamed(&self, reader: &mut Reader, parts: &[Part]) -> StreamedAnswers {
+        (self.solve_streamed)(reader, parts)
+    }
+
+    /// Replays one part for an input returned by `parse` of the same day.
+    pub fn visualise(&self, input: &dyn Any, part: Part, frames: &mut dyn Frames) -> Result<()> {
+        (self.visualise)(input, part, frames)
+    }
+
+    /// Checks the rules shared by all inputs read from `source`, then those
+    /// of the day unless there is nothing to check. The violations are in
+    /// the order of their position, those of the whole input last.
+    pub fn validate(&self, data: &str, source: &Source) -> Vec<Error> {
+        let mut errors = validate::common(data, source);
+        if !data.trim().is_empty() {
+            errors.extend((self.validate)(data));
+        }
+        validate::sort(&mut errors);
+        errors
+    }
+
+    /// A random input, with the parameters it was generated for.
+    pub fn generate(&self, rng: &mut Rng, size: Size) -> Result<Input> {
+        Ok(Input {
+            name: "<generated>".to_string(),
+            data: (self.generate)(rng, size)?,
+            params: (self.generated_params)(size),
+        })
+    }
+}
+
+fn parse_erased<S>(data: &str, params: &Params) -> Result<Box<dyn Any>>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse_with(data, params)?))
+}
+
+fn solve_erased<S>(input: &dyn Any, part: Part) -> Result<String>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input parsed by another day");
+    Ok(match part {
+        Part::One => S::part1(input)?.to_string(),
+        Part::Two => S::part2(input)?.to_string(),
+    })
+}
+
+fn visualise_erased<S>(input: &dyn Any, part: Part, frames: &mut dyn Frames) -> Result<()>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input parsed by another day");
+    S::visualise(input, part, frames)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day;
+    use super::Params;
+    use super::Part;
+    use super::Solution;
+    use super::Source;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = usize;
+
+        fn parse(data: &str) -> crate::Result<Self::Input> {
+            crate::parse_lines(data)
+        }
+
+        fn part1(input: &Self::Input) -> crate::Result<u32> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> crate::Result<usize> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn test_day() {
+        let day = Day::new::<Sum>(1);
+        let input = day.parse("1\n2\n3", &Params::default()).unwrap();
+        assert_eq!(day.solve(input.as_ref(), Part::One).unwrap(), "6");
+        assert_eq!(day.solve(input.as_ref(), Part::Two).unwrap(), "3");
+        assert!(day.validate("1\n2\n3\n", &Source::Default).is_empty());
+        let errors = day.validate("1\nx\n3", &Source::Default);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0].to_string(),
+            "line 2: invalid digit found in string in \"x\""
+        );
+    }
+}
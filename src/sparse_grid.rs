@@ -0,0 +1,250 @@
+use crate::geom::point2;
+use crate::geom::Direction4;
+use crate::geom::Direction8;
+use crate::geom::Point2;
+use crate::grid::Grid;
+use std::collections::HashMap;
+
+/// The smallest rectangle holding every cell, with inclusive corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+/// A map of cells that only stores the occupied ones, for puzzles where the
+/// extent of the map is unknown up front or mostly empty.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+    bounds: Option<Bounds>,
+}
+
+impl Bounds {
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    /// Whether `pos` lies on the border, so that removing it may shrink the box.
+    fn touches(&self, p: Point2) -> bool {
+        p.x == self.min.x || p.x == self.max.x || p.y == self.min.y || p.y == self.max.y
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The bounding box of all cells, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn contains(&self, pos: Point2) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Point2) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Point2) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Point2, value: T) -> Option<T> {
+        self.bounds = Some(extend(self.bounds, pos));
+        self.cells.insert(pos, value)
+    }
+
+    /// Removes a cell; the bounding box is recomputed only when the cell
+    /// was on its border.
+    pub fn remove(&mut self, pos: Point2) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+        if self.bounds.is_some_and(|b| b.touches(pos)) {
+            self.bounds = self.cells.keys().fold(None, |b, &pos| Some(extend(b, pos)));
+        }
+        Some(value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The occupied orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Point2) -> impl Iterator<Item = (Point2, &T)> {
+        self.occupied(Direction4::ALL.iter().map(move |d| pos + d.step()))
+    }
+
+    /// The occupied orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: Point2) -> impl Iterator<Item = (Point2, &T)> {
+        self.occupied(Direction8::ALL.iter().map(move |d| pos + d.step()))
+    }
+
+    /// The occupied cells among `positions`.
+    fn occupied(
+        &self,
+        positions: impl Iterator<Item = Point2>,
+    ) -> impl Iterator<Item = (Point2, &T)> {
+        positions.filter_map(move |pos| self.get(pos).map(|value| (pos, value)))
+    }
+
+    /// The first occupied cell of column `x` met going from row `from`
+    /// down (`forward`) or up.
+    pub fn first_in_column(&self, x: i64, from: i64, forward: bool) -> Option<(Point2, &T)> {
+        let bounds = self.bounds.filter(|b| (b.min.x..=b.max.x).contains(&x))?;
+        self.occupied(scan(from, forward, bounds.min.y, bounds.max.y).map(|y| point2(x, y)))
+            .next()
+    }
+
+    /// The first occupied cell of row `y` met going from column `from`
+    /// right (`forward`) or left.
+    pub fn first_in_row(&self, y: i64, from: i64, forward: bool) -> Option<(Point2, &T)> {
+        let bounds = self.bounds.filter(|b| (b.min.y..=b.max.y).contains(&y))?;
+        self.occupied(scan(from, forward, bounds.min.x, bounds.max.x).map(|x| point2(x, y)))
+            .next()
+    }
+
+    /// Draws the bounding box row by row, choosing each character with
+    /// `glyph`, which gets `None` for empty cells.
+    pub fn render(&self, glyph: impl Fn(Option<&T>) -> char) -> String {
+        let mut text = String::new();
+        if let Some(bounds) = self.bounds {
+            for y in bounds.min.y..=bounds.max.y {
+                for x in bounds.min.x..=bounds.max.x {
+                    text.push(glyph(self.get(point2(x, y))));
+                }
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    /// Copies the cells inside `bounds` into a dense grid, converting each
+    /// with `cell`, which gets `None` for empty cells.
+    pub fn to_grid<U>(&self, bounds: Bounds, cell: impl Fn(Option<&T>) -> U) -> Grid<U> {
+        let mut cells = Vec::with_capacity((bounds.width() * bounds.height()) as usize);
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                cells.push(cell(self.get(point2(x, y))));
+            }
+        }
+        Grid::from_vec(bounds.width() as usize, cells).expect("bounds are not empty")
+    }
+}
+
+/// Grows `bounds` to hold `pos`.
+fn extend(bounds: Option<Bounds>, p: Point2) -> Bounds {
+    match bounds {
+        Some(b) => Bounds {
+            min: point2(b.min.x.min(p.x), b.min.y.min(p.y)),
+            max: point2(b.max.x.max(p.x), b.max.y.max(p.y)),
+        },
+        None => Bounds { min: p, max: p },
+    }
+}
+
+/// The values from `from` towards `max` (`forward`) or `min`, clipped to
+/// `min..=max`.
+fn scan(from: i64, forward: bool, min: i64, max: i64) -> impl Iterator<Item = i64> {
+    let (low, high) = if forward {
+        (from.max(min), max)
+    } else {
+        (min, from.min(max))
+    };
+    (low..=high).map(move |v| if forward { v } else { low + high - v })
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Bounds;
+    use super::SparseGrid;
+    use crate::geom::point2;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(point2(2, -1), 'a');
+        grid.insert(point2(-3, 4), 'b');
+        grid.insert(point2(0, 0), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: point2(-3, -1),
+                max: point2(2, 4)
+            })
+        );
+        grid.remove(point2(0, 0));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: point2(-3, -1),
+                max: point2(2, 4)
+            })
+        );
+        grid.remove(point2(-3, 4));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: point2(2, -1),
+                max: point2(2, -1)
+            })
+        );
+        grid.remove(point2(2, -1));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_scans_and_render() {
+        let mut grid = SparseGrid::new();
+        grid.insert(point2(0, 0), '#');
+        grid.insert(point2(2, 0), '#');
+        grid.insert(point2(1, 2), 'o');
+        assert_eq!(
+            grid.first_in_column(1, -5, true),
+            Some((point2(1, 2), &'o'))
+        );
+        assert_eq!(grid.first_in_column(1, 1, false), None);
+        assert_eq!(grid.first_in_column(5, 0, true), None);
+        assert_eq!(grid.first_in_row(0, 1, true), Some((point2(2, 0), &'#')));
+        assert_eq!(grid.first_in_row(0, 9, false), Some((point2(2, 0), &'#')));
+        assert_eq!(grid.neighbours8(point2(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours4(point2(1, 1)).count(), 1);
+        assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "#.#\n...\n.o.\n");
+        let window = Bounds {
+            min: point2(1, -1),
+            max: point2(2, 0),
+        };
+        assert_eq!(
+            grid.to_grid(window, |c| *c.unwrap_or(&'.')).to_string(),
+            "..\n.#"
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use crate::input;
+use crate::Error;
+use crate::Result;
+use flate2::read::MultiGzDecoder;
+use std::fs::File;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The first bytes of every gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// One line of a streamed input, without its line ending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// The 1-based line number.
+    pub number: usize,
+    pub text: String,
+}
+
+/// An input read as it goes, with a name to use in error messages. It
+/// hashes the bytes passing through like `Input::hash`.
+pub struct Reader {
+    inner: Box<dyn BufRead>,
+    name: String,
+    hash: u64,
+    large: bool,
+}
+
+/// Iterator over the lines of a reader that keeps only the current line in
+/// memory, unlike `str::lines` on a whole input.
+pub struct Lines<R> {
+    reader: R,
+    name: String,
+    number: usize,
+}
+
+/// Opens `path` for reading line by line; `-` is stdin. Gzip-compressed
+/// data is recognised by its magic bytes and decompressed on the fly.
+pub fn open(path: &Path) -> Result<Reader> {
+    let name = if path == Path::new("-") {
+        "<stdin>".to_string()
+    } else {
+        path.display().to_string()
+    };
+    let io_error = |source| Error::Io {
+        path: name.clone(),
+        source,
+    };
+    let is_stdin = path == Path::new("-");
+    let mut reader: Box<dyn BufRead> = if is_stdin {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(BufReader::new(File::open(path).map_err(io_error)?))
+    };
+    let compressed = reader
+        .fill_buf()
+        .map_err(io_error)?
+        .starts_with(&GZIP_MAGIC);
+    if compressed {
+        reader = Box::new(BufReader::new(MultiGzDecoder::new(reader)));
+    }
+    let mut reader = Reader::new(reader, &name);
+    reader.large = is_stdin || compressed;
+    Ok(reader)
+}
+
+/// Streams the lines of `path`, see `open`.
+pub fn lines(path: &Path) -> Result<Lines<Reader>> {
+    Ok(open(path)?.lines())
+}
+
+impl Reader {
+    pub fn new(inner: Box<dyn BufRead>, name: &str) -> Self {
+        Reader {
+            inner,
+            name: name.to_string(),
+            hash: input::HASH_START,
+            large: false,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the input may be too large to hold in memory: stdin and
+    /// gzip-compressed data, whatever the file is called. The runner
+    /// streams these to days that can.
+    pub fn is_large(&self) -> bool {
+        self.large
+    }
+
+    /// The hash of the bytes read so far.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// The remaining bytes, one at a time.
+    pub fn bytes(&mut self) -> impl Iterator<Item = Result<u8>> + '_ {
+        let name = self.name.clone();
+        Read::bytes(self).map(move |b| {
+            b.map_err(|source| Error::Io {
+                path: name.clone(),
+                source,
+            })
+        })
+    }
+
+    pub fn lines(self) -> Lines<Self> {
+        let name = self.name.clone();
+        Lines::new(self, &name)
+    }
+
+    /// Reads the rest of the input whole.
+    pub fn read_to_string(mut self) -> Result<String> {
+        let mut data = String::new();
+        Read::read_to_string(&mut self, &mut data).map_err(|source| Error::Io {
+            path: self.name.clone(),
+            source,
+        })?;
+        Ok(data)
+    }
+
+    /// Reads the rest of the input without keeping it, to finish its hash.
+    pub fn skip_to_end(&mut self) -> Result<()> {
+        io::copy(self, &mut io::sink())
+            .map(|_| ())
+            .map_err(|source| Error::Io {
+                path: self.name.clone(),
+                source,
+            })
+    }
+}
+
+impl Read for Reader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.hash = input::hash_bytes(self.hash, &buf[..count]);
+        Ok(count)
+    }
+}
+
+impl BufRead for Reader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amount: usize) {
+        // The bytes were buffered by the last `fill_buf`, so this reads nothing.
+        if let Ok(buf) = self.inner.fill_buf() {
+            self.hash = input::hash_bytes(self.hash, &buf[..amount.min(buf.len())]);
+        }
+        self.inner.consume(amount);
+    }
+}
+
+impl Line {
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Into<Error>,
+    {
+        self.text
+            .parse::<T>()
+            .map_err(|e| e.into().at_line(self.number, &self.text))
+    }
+}
+
+impl<R: BufRead> Lines<R> {
+    /// Reads lines from `reader`, using `name` in error messages.
+    pub fn new(reader: R, name: &str) -> Self {
+        Lines {
+            reader,
+            name: name.to_string(),
+            number: 0,
+        }
+    }
+
+    /// Parses every line with `T::from_str`, one at a time.
+    pub fn parse<T>(self) -> impl Iterator<Item = Result<T>>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Into<Error>,
+    {
+        let name = self.name.clone();
+        self.map(move |line| line.and_then(|l| l.parse()).map_err(|e| e.in_file(&name)))
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<Line>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut text = String::new();
+        match self.reader.read_line(&mut text) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.number += 1;
+                if text.ends_with('\n') {
+                    text.pop();
+                    if text.ends_with('\r') {
+                        text.pop();
+                    }
+                }
+                Some(Ok(Line {
+                    number: self.number,
+                    text,
+                }))
+            }
+            Err(source) => Some(Err(Error::Io {
+                path: self.name.clone(),
+                source,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::lines;
+    use super::open;
+    use super::Line;
+    use super::Lines;
+    use super::Reader;
+    use crate::input::Input;
+    use crate::params::Params;
+    use crate::site::mock;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::fs;
+    use std::io::Write;
+
+    #[test]
+    fn test_lines() {
+        let lines = Lines::new("1\r\n\n3".as_bytes(), "input.txt")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            lines,
+            vec![
+                Line {
+                    number: 1,
+                    text: "1".to_string()
+                },
+                Line {
+                    number: 2,
+                    text: "".to_string()
+                },
+                Line {
+                    number: 3,
+                    text: "3".to_string()
+                },
+            ]
+        );
+        let err = Lines::new("1\nx\n".as_bytes(), "input.txt")
+            .parse::<u32>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input.txt:2: invalid digit found in string in \"x\""
+        );
+    }
+
+    #[test]
+    fn test_reader_hash() {
+        let data = "abc\ndef\n";
+        let input = Input {
+            name: String::new(),
+            data: data.to_string(),
+            params: Params::default(),
+        };
+        let mut reader = Reader::new(Box::new(data.as_bytes()), "input.txt");
+        assert_eq!(reader.bytes().next().unwrap().unwrap(), b'a');
+        let mut line = String::new();
+        std::io::BufRead::read_line(&mut reader, &mut line).unwrap();
+        reader.skip_to_end().unwrap();
+        assert_eq!(reader.hash(), input.hash());
+    }
+
+    #[test]
+    fn test_gzip() {
+        let dir = mock::temp_dir("stream");
+        let path = dir.join("input.txt.gz");
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"10\n20\n30\n").unwrap();
+        fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let values = lines(&path)
+            .unwrap()
+            .parse::<u32>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(values, vec![10, 20, 30]);
+        assert!(open(&path).unwrap().is_large());
+        assert_eq!(
+            open(&path).unwrap().read_to_string().unwrap(),
+            "10\n20\n30\n"
+        );
+
+        // The magic bytes decide, not the name.
+        let renamed = dir.join("input.txt");
+        fs::rename(&path, &renamed).unwrap();
+        let reader = open(&renamed).unwrap();
+        assert!(reader.is_large());
+        assert_eq!(reader.read_to_string().unwrap(), "10\n20\n30\n");
+        let plain = dir.join("plain.gz");
+        fs::write(&plain, "10\n").unwrap();
+        assert!(!open(&plain).unwrap().is_large());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,336 @@
+use crate::site::Client;
+use crate::site::YEAR;
+use crate::template::Template;
+use crate::Error;
+use crate::Part;
+use crate::Result;
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Every submitted answer and its outcome, one `<day> <part> <outcome> <answer>`
+/// entry per line.
+pub const LOG_PATH: &str = "data/submissions.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited {
+        wait: Duration,
+    },
+    /// The response did not match any known message; holds its text.
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u32,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+pub struct Log {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+impl Outcome {
+    /// Classifies the HTML page returned for a submitted answer.
+    pub fn classify(html: &str) -> Outcome {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait: parse_wait(&text).unwrap_or_default(),
+            }
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        match self {
+            Outcome::Correct => Some("correct"),
+            Outcome::TooHigh => Some("too-high"),
+            Outcome::TooLow => Some("too-low"),
+            Outcome::Wrong => Some("wrong"),
+            Outcome::RateLimited { .. } | Outcome::Unknown(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait } => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            Outcome::Unknown(text) => write!(f, "unrecognised response: {}", text),
+        }
+    }
+}
+
+/// Extracts the text of the page's `<article>` (or the whole page if there
+/// is none) with tags removed and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            let end = html[start..].find("</article>")?;
+            Some(&html[start..start + end])
+        })
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the "You have 1m 5s left to wait" part of a rate limit message.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (value, unit) = [("h", 3600), ("m", 60), ("s", 1)]
+            .iter()
+            .find_map(|&(suffix, unit)| Some((token.strip_suffix(suffix)?, unit)))?;
+        seconds += value.parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+impl FromStr for Submission {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let captures = Template::new("{} {} {} {}").captures(s)?;
+        let outcome = captures.get(2);
+        let outcome = match outcome {
+            "correct" => Outcome::Correct,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            _ => {
+                return Err(Error::unexpected_in(
+                    s,
+                    outcome,
+                    "correct, too-high, too-low or wrong",
+                ))
+            }
+        };
+        Ok(Submission {
+            day: captures.parse(0)?,
+            part: captures.parse(1)?,
+            outcome,
+            answer: captures.parse(3)?,
+        })
+    }
+}
+
+impl Log {
+    /// Reads the log at `path`; a missing file is an empty log.
+    pub fn open(path: &Path) -> Result<Self> {
+        let name = path.display().to_string();
+        let entries = if path.exists() {
+            crate::read_one_per_non_empty_line(&name)?
+        } else {
+            Vec::new()
+        };
+        Ok(Log {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    pub fn entries(&self) -> &[Submission] {
+        &self.entries
+    }
+
+    /// Finds an earlier submission proving `answer` wrong: the same value
+    /// was rejected, or a numeric answer is past a known too-high or too-low one.
+    pub fn known_wrong(&self, day: u32, part: Part, answer: &str) -> Option<&Submission> {
+        let value = answer.parse::<i64>().ok();
+        self.entries
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .find(|s| {
+                let previous = s.answer.parse::<i64>().ok();
+                match (&s.outcome, value, previous) {
+                    (outcome, _, _) if outcome.is_wrong() && s.answer == answer => true,
+                    (Outcome::TooHigh, Some(v), Some(p)) => v >= p,
+                    (Outcome::TooLow, Some(v), Some(p)) => v <= p,
+                    _ => false,
+                }
+            })
+    }
+
+    /// Appends a submission to the log file, unless its outcome says
+    /// nothing about the answer (rate limits and unrecognised responses).
+    pub fn record(&mut self, submission: Submission) -> Result<()> {
+        let label = match submission.outcome.label() {
+            Some(label) => label,
+            None => return Ok(()),
+        };
+        let line = format!(
+            "{} {} {} {}\n",
+            submission.day, submission.part, label, submission.answer
+        );
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|source| Error::Io {
+                path: self.path.display().to_string(),
+                source,
+            })?;
+        self.entries.push(submission);
+        Ok(())
+    }
+}
+
+/// Submits an answer unless the log already proves it wrong, and records
+/// the outcome.
+pub fn submit(
+    client: &Client,
+    log: &mut Log,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(Error::structure(format!("refusing to submit {:?}", answer)));
+    }
+    if let Some(previous) = log.known_wrong(day, part, answer) {
+        return Err(Error::structure(format!(
+            "refusing to submit {} for day {} part {}: {} was {}",
+            answer, day, part, previous.answer, previous.outcome
+        )));
+    }
+    let level = part.to_string();
+    let html = client.post_form(
+        &format!("/{}/day/{}/answer", YEAR, day),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let outcome = Outcome::classify(&html);
+    log.record(Submission {
+        day,
+        part,
+        outcome: outcome.clone(),
+        answer: answer.to_string(),
+    })?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_wait;
+    use super::submit;
+    use super::Log;
+    use super::Outcome;
+    use crate::site::mock;
+    use crate::site::Client;
+    use crate::Part;
+    use std::fs;
+    use std::time::Duration;
+
+    const TOO_HIGH: &str = "<html><main><article><p>That's not the right answer; \
+        your answer is too high.  If you're stuck, ... please wait one minute \
+        before trying again. <a href=\"/2022/day/7\">[Return to Day 7]</a></p>\
+        </article></main></html>";
+    const CORRECT: &str =
+        "<article><p>That's the right answer!  You are one gold star closer.</p></article>";
+    const TOO_RECENTLY: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 5s left to wait.\
+        </p></article>";
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(Outcome::classify(TOO_HIGH), Outcome::TooHigh);
+        assert_eq!(Outcome::classify(CORRECT), Outcome::Correct);
+        assert_eq!(
+            Outcome::classify(TOO_RECENTLY),
+            Outcome::RateLimited {
+                wait: Duration::from_secs(65)
+            }
+        );
+        assert_eq!(
+            Outcome::classify("<article><p>Something   <em>else</em></p></article>"),
+            Outcome::Unknown("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        let wait = |text: &str| parse_wait(text).map(|d| d.as_secs());
+        assert_eq!(wait("You have 1h 2m 5s left to wait."), Some(3725));
+        assert_eq!(wait("You have 5é left to wait."), None);
+        assert_eq!(wait("You have 5 left to wait."), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) =
+            mock::serve(vec![(200, TOO_HIGH), (200, TOO_RECENTLY), (200, CORRECT)]);
+        let client = Client::new(&base_url, "abc123");
+        let dir = mock::temp_dir("submit");
+        let path = dir.join("submissions.txt");
+        let mut log = Log::open(&path).unwrap();
+
+        assert_eq!(
+            submit(&client, &mut log, 7, Part::One, "1000").unwrap(),
+            Outcome::TooHigh
+        );
+        // Known to be wrong: neither call reaches the server.
+        assert!(submit(&client, &mut log, 7, Part::One, "1000").is_err());
+        assert!(submit(&client, &mut log, 7, Part::One, "1200").is_err());
+        assert!(matches!(
+            submit(&client, &mut log, 7, Part::One, "900").unwrap(),
+            Outcome::RateLimited { .. }
+        ));
+        assert_eq!(
+            submit(&client, &mut log, 7, Part::One, "900").unwrap(),
+            Outcome::Correct
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("POST /2022/day/7/answer "));
+        assert!(requests[0].ends_with("level=1&answer=1000"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "7 1 too-high 1000\n7 1 correct 900\n"
+        );
+        assert_eq!(Log::open(&path).unwrap().entries(), log.entries());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,253 @@
+use crate::Error;
+use crate::Result;
+use std::str::FromStr;
+
+/// A line format made of literal text and `{}` placeholders, such as
+/// `"move {} from {} to {}"`. A placeholder captures everything up to the
+/// literal text that follows it, or up to the end of the line if it is last.
+#[derive(Debug, Clone, Copy)]
+pub struct Template {
+    pattern: &'static str,
+}
+
+/// The text captured by the placeholders of a template in one line.
+#[derive(Debug)]
+pub struct Captures<'a> {
+    line: &'a str,
+    values: Vec<&'a str>,
+}
+
+/// Types that can be built from all the captures of a line at once; see
+/// `Template::parse`.
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &Captures) -> Result<Self>;
+}
+
+impl Template {
+    /// Panics, at compile time for constants, if two placeholders are
+    /// adjacent: the first could not tell where its capture ends.
+    pub const fn new(pattern: &'static str) -> Self {
+        let bytes = pattern.as_bytes();
+        let mut i = 0;
+        while i + 4 <= bytes.len() {
+            if bytes[i] == b'{'
+                && bytes[i + 1] == b'}'
+                && bytes[i + 2] == b'{'
+                && bytes[i + 3] == b'}'
+            {
+                panic!("adjacent placeholders in a template");
+            }
+            i += 1;
+        }
+        Template { pattern }
+    }
+
+    /// Matches `line` against the template. Errors point at the first
+    /// character that does not fit.
+    pub fn captures<'a>(&self, line: &'a str) -> Result<Captures<'a>> {
+        let mut literals = self.pattern.split("{}");
+        let mut rest = expect_literal(line, line, literals.next().unwrap_or(""))?;
+        let mut values = Vec::new();
+        for literal in literals {
+            // `new` leaves only the last placeholder without literal text
+            // after it.
+            let end = if literal.is_empty() {
+                rest.len()
+            } else {
+                // Without the literal, capture a word so the error points
+                // at what follows it.
+                rest.find(literal)
+                    .or_else(|| rest.find(char::is_whitespace))
+                    .unwrap_or(rest.len())
+            };
+            if end == 0 {
+                return Err(if rest.is_empty() {
+                    Error::end_of_line(line, "a value")
+                } else {
+                    Error::unexpected_in(line, first_token(rest), "a value")
+                });
+            }
+            values.push(&rest[..end]);
+            rest = expect_literal(line, &rest[end..], literal)?;
+        }
+        if !rest.is_empty() {
+            return Err(Error::unexpected_in(line, first_token(rest), "end of line"));
+        }
+        Ok(Captures { line, values })
+    }
+
+    /// Matches `line` and parses its captures into a tuple, for example
+    /// `let (x, y): (i64, i64) = Template::new("{},{}").parse(line)?;`.
+    pub fn parse<T: FromCaptures>(&self, line: &str) -> Result<T> {
+        T::from_captures(&self.captures(line)?)
+    }
+}
+
+impl<'a> Captures<'a> {
+    /// The matched line.
+    pub fn line(&self) -> &'a str {
+        self.line
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The text of capture `i`, a slice of the matched line.
+    pub fn get(&self, i: usize) -> &'a str {
+        self.values[i]
+    }
+
+    /// Parses capture `i`. Errors of `T` that carry a column are moved to
+    /// the position of the capture; others become an error naming the
+    /// expected type at that position.
+    pub fn parse<T>(&self, i: usize) -> Result<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Into<Error>,
+    {
+        let value = self.values[i];
+        value.parse::<T>().map_err(|e| match e.into() {
+            e @ Error::UnexpectedToken { .. } => e.offset(crate::slice_offset(self.line, value)),
+            _ => Error::unexpected_in(self.line, value, &describe::<T>()),
+        })
+    }
+
+    /// Fails unless the template had exactly `count` placeholders.
+    fn expect_count(&self, count: usize) -> Result<()> {
+        if self.values.len() == count {
+            Ok(())
+        } else {
+            Err(Error::structure(format!(
+                "expected {} captures, found {}",
+                count,
+                self.values.len()
+            )))
+        }
+    }
+}
+
+/// Strips `literal` from the start of `rest`, a tail of `line`.
+fn expect_literal<'a>(line: &str, rest: &'a str, literal: &str) -> Result<&'a str> {
+    rest.strip_prefix(literal).ok_or_else(|| {
+        let expected = format!("{:?}", literal);
+        if rest.is_empty() {
+            Error::end_of_line(line, &expected)
+        } else {
+            Error::unexpected_in(line, first_token(rest), &expected)
+        }
+    })
+}
+
+/// The first word of `s`, or its first character if that is not part of a
+/// word.
+fn first_token(s: &str) -> &str {
+    let word = s.trim_start();
+    let end = word.find(char::is_whitespace).unwrap_or(word.len());
+    if end > 0 {
+        &word[..end]
+    } else {
+        &s[..s.chars().next().map_or(0, char::len_utf8)]
+    }
+}
+
+/// A description of `T` for error messages.
+fn describe<T>() -> String {
+    let name = std::any::type_name::<T>();
+    let name = name.rsplit("::").next().unwrap_or(name);
+    match name {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+        | "isize" | "f32" | "f64" => "a number".to_string(),
+        "char" => "a character".to_string(),
+        name => format!("a {}", name),
+    }
+}
+
+/// Implements `FromCaptures` for a tuple whose items parse captures in order.
+macro_rules! impl_from_captures {
+    ($count:literal: $($t:ident $i:tt),+) => {
+        impl<$($t),+> FromCaptures for ($($t,)+)
+        where
+            $($t: FromStr, <$t as FromStr>::Err: Into<Error>,)+
+        {
+            fn from_captures(captures: &Captures) -> Result<Self> {
+                captures.expect_count($count)?;
+                Ok(($(captures.parse::<$t>($i)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1: A 0);
+impl_from_captures!(2: A 0, B 1);
+impl_from_captures!(3: A 0, B 1, C 2);
+impl_from_captures!(4: A 0, B 1, C 2, D 3);
+impl_from_captures!(5: A 0, B 1, C 2, D 3, E 4);
+
+#[cfg(test)]
+mod test {
+    use super::Template;
+    use crate::geom::Direction4;
+
+    const MOVE: Template = Template::new("move {} from {} to {}");
+
+    #[test]
+    fn test_parse() {
+        let (amount, from, to): (u32, usize, String) = MOVE.parse("move 3 from 1 to 9x").unwrap();
+        assert_eq!((amount, from, to.as_str()), (3, 1, "9x"));
+        let (d, n): (Direction4, u8) = Template::new("{} {}").parse("R 4").unwrap();
+        assert_eq!((d, n), (Direction4::Right, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent placeholders")]
+    fn test_adjacent_placeholders() {
+        Template::new("{}{} {}");
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |line| MOVE.parse::<(u32, u32, u32)>(line).unwrap_err().to_string();
+        assert_eq!(
+            error("move 3 from x to 9"),
+            "column 13: expected a number, found \"x\""
+        );
+        assert_eq!(
+            error("move 3 to 1 to 9"),
+            "column 8: expected \" from \", found \"to\""
+        );
+        assert_eq!(
+            error("move 3 from 1"),
+            "column 14: expected \" to \", found end of line"
+        );
+        assert_eq!(
+            error("push 3 from 1 to 2"),
+            "column 1: expected \"move \", found \"push\""
+        );
+        assert_eq!(
+            Template::new("{} {}")
+                .parse::<(Direction4, u8)>("R Q")
+                .unwrap_err()
+                .to_string(),
+            "column 3: expected a number, found \"Q\""
+        );
+        assert_eq!(
+            Template::new("{} {}")
+                .parse::<(u8, Direction4)>("4 Q")
+                .unwrap_err()
+                .to_string(),
+            "column 3: expected U, D, L, R, ^, v, < or >, found \"Q\""
+        );
+        assert_eq!(
+            Template::new("x={}")
+                .parse::<(i32,)>("x=1 y=2")
+                .unwrap_err()
+                .to_string(),
+            "column 3: expected a number, found \"1 y=2\""
+        );
+    }
+}
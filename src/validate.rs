@@ -0,0 +1,172 @@
+use crate::input::Source;
+use crate::Error;
+use std::str::FromStr;
+use std::str::Lines;
+
+/// Rules shared by every input: it is not empty, uses Unix line endings
+/// and ends with a line break, which a truncated copy usually lacks. Text
+/// given on the command line is not a copy and may leave the break out.
+pub fn common(data: &str, source: &Source) -> Vec<Error> {
+    if data.trim().is_empty() {
+        return vec![Error::structure("the input is empty")];
+    }
+    let mut errors = Vec::new();
+    let mut count = 0;
+    for (i, line) in data.split('\n').enumerate() {
+        if let Some(column) = line.find('\r') {
+            let text = line.trim_end_matches('\r');
+            errors.push(
+                Error::unexpected(column + 1, "\r", "a Unix line ending").at_line(i + 1, text),
+            );
+        }
+        count = i;
+    }
+    if !data.ends_with('\n') && !matches!(source, Source::Text(_)) {
+        let last = data.lines().last().unwrap_or("");
+        let message = "missing line break at the end, the input may be truncated";
+        errors.push(Error::structure(message).at_line(count + 1, last));
+    }
+    errors
+}
+
+/// Runs `check` on every line of `lines`, numbering them from `first_line`,
+/// and locates the violations it returns at their line.
+pub fn check_lines<'a>(
+    first_line: usize,
+    lines: Lines<'a>,
+    mut check: impl FnMut(&'a str) -> Vec<Error>,
+) -> Vec<Error> {
+    lines
+        .enumerate()
+        .flat_map(|(i, line)| {
+            check(line)
+                .into_iter()
+                .map(move |e| e.at_line(first_line + i, line))
+        })
+        .collect()
+}
+
+/// Orders violations by line and column, those of the whole input last.
+pub fn sort(errors: &mut [Error]) {
+    errors.sort_by_key(|e| {
+        let (line, column) = e.position();
+        (line.unwrap_or(usize::MAX), column.unwrap_or(usize::MAX))
+    });
+}
+
+/// The error of parsing `line` as a `T`, if any. This is how days check
+/// the vocabulary of their commands: the parsers already know it.
+pub fn parses<T>(line: &str) -> Vec<Error>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Error>,
+{
+    line.parse::<T>()
+        .err()
+        .map(Into::into)
+        .into_iter()
+        .collect()
+}
+
+/// Every character of `line` that `valid` rejects; `expected` describes
+/// what it accepts.
+pub fn chars(line: &str, expected: &str, valid: impl Fn(char) -> bool) -> Vec<Error> {
+    line.char_indices()
+        .filter(|&(_, c)| !valid(c))
+        .map(|(column, c)| Error::unexpected(column + 1, &c.to_string(), expected))
+        .collect()
+}
+
+/// The rules of `Grid::parse`, but reporting every bad character and every
+/// row whose width differs from the first one instead of stopping early.
+pub fn grid(data: &str, expected: &str, valid: impl Fn(char) -> bool) -> Vec<Error> {
+    let rows = data.trim_end_matches('\n');
+    let width = rows.lines().next().unwrap_or("").chars().count();
+    check_lines(1, rows.lines(), |line| {
+        let mut errors = chars(line, expected, &valid);
+        let count = line.chars().count();
+        if count != width {
+            errors.push(Error::structure(format!(
+                "expected a row of {} cells, found {}",
+                width, count
+            )));
+        }
+        errors
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::check_lines;
+    use super::common;
+    use super::grid;
+    use super::parses;
+    use super::sort;
+    use crate::input::Source;
+    use crate::Error;
+
+    fn messages(errors: Vec<Error>) -> Vec<String> {
+        errors.iter().map(Error::to_string).collect()
+    }
+
+    #[test]
+    fn test_common() {
+        let file = Source::Default;
+        assert!(common("1\n2\n", &file).is_empty());
+        assert_eq!(messages(common(" \n", &file)), vec!["the input is empty"]);
+        assert!(common("1\n2", &Source::Text("1\n2".to_string())).is_empty());
+        assert_eq!(
+            messages(common("1\r\n2\r\n3", &file)),
+            vec![
+                "line 1: column 2: expected a Unix line ending, found \"\\r\" in \"1\"",
+                "line 2: column 2: expected a Unix line ending, found \"\\r\" in \"2\"",
+                "line 3: missing line break at the end, the input may be truncated in \"3\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_lines() {
+        let errors = check_lines(4, "1\nx\n3\ny".lines(), parses::<u32>);
+        assert_eq!(
+            messages(errors),
+            vec![
+                "line 5: invalid digit found in string in \"x\"",
+                "line 7: invalid digit found in string in \"y\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut errors = vec![
+            Error::structure("whole"),
+            Error::unexpected(4, "x", "y").at_line(2, "abcx"),
+            Error::structure("row").at_line(1, "a"),
+            Error::unexpected(2, "x", "y").at_line(2, "ax"),
+        ];
+        sort(&mut errors);
+        assert_eq!(
+            messages(errors),
+            vec![
+                "line 1: row in \"a\"",
+                "line 2: column 2: expected y, found \"x\" in \"ax\"",
+                "line 2: column 4: expected y, found \"x\" in \"abcx\"",
+                "whole",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        assert!(grid("123\n456\n", "a digit", |c| c.is_ascii_digit()).is_empty());
+        let errors = grid("123\n4x6\n78\n", "a digit", |c| c.is_ascii_digit());
+        assert_eq!(
+            messages(errors),
+            vec![
+                "line 2: column 2: expected a digit, found \"x\" in \"4x6\"",
+                "line 3: expected a row of 3 cells, found 2 in \"78\"",
+            ]
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use aoc::answers;
+use aoc::days;
+
+#[test]
+fn recorded_answers() {
+    let expected = answers::read(answers::MANIFEST_PATH).unwrap();
+    // A day fresh from `aoc new` has none until its answers are known.
+    for day in days::ALL {
+        if !expected.iter().any(|a| a.day == day.number) {
+            eprintln!("note: no recorded answers for day {}, skipped", day.number);
+        }
+    }
+
+    let mismatches = answers::check(&expected);
+    let report = mismatches
+        .iter()
+        .map(|m| m.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(
+        mismatches.is_empty(),
+        "{} mismatches:\n{}",
+        mismatches.len(),
+        report
+    );
+}